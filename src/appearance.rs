@@ -0,0 +1,759 @@
+//! Colour appearance models: CAM16 and CIECAM02.
+//!
+//! Both models predict the appearance correlates of a stimulus (lightness,
+//! chroma, hue, colourfulness, saturation and brightness) under a given set
+//! of [ViewingConditions]. CAM16 is the recommended model. CIECAM02 is
+//! provided for compatibility with existing data.
+//!
+//! See Li et al., "Comprehensive color solutions: CAM16, CAT16, and
+//! CAM16-UCS" (2017), and CIE 159:2004.
+#![allow(non_snake_case)]
+#![allow(clippy::excessive_precision, clippy::unreadable_literal)]
+use super::math::*;
+use super::xyz::*;
+
+use numeric_literals::replace_float_literals;
+
+/// Describes the surround of a stimulus: the degree of adaptation factor
+/// `F`, the impact of surround `c` and the chromatic induction factor `N_c`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Surround<T>
+where
+    T: Real,
+{
+    pub F: T,
+    pub c: T,
+    pub N_c: T,
+}
+
+impl<T> Surround<T>
+where
+    T: Real,
+{
+    /// Average surround, e.g. viewing surface colours
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn average() -> Surround<T> {
+        Surround {
+            F: 1.0,
+            c: 0.69,
+            N_c: 1.0,
+        }
+    }
+
+    /// Dim surround, e.g. viewing television
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn dim() -> Surround<T> {
+        Surround {
+            F: 0.9,
+            c: 0.59,
+            N_c: 0.9,
+        }
+    }
+
+    /// Dark surround, e.g. projection in a darkened room
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn dark() -> Surround<T> {
+        Surround {
+            F: 0.8,
+            c: 0.525,
+            N_c: 0.8,
+        }
+    }
+}
+
+/// The conditions under which a stimulus is viewed
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ViewingConditions<T>
+where
+    T: Real,
+{
+    /// XYZ of the adopted white, with Y on the same scale as the stimulus
+    pub white: XYZ<T>,
+    /// Luminance of the adapting field in cd/m², normally 20% of the
+    /// luminance of a white object in the scene
+    pub L_A: T,
+    /// Relative luminance of the background, on the same scale as `white`
+    pub Y_b: T,
+    pub surround: Surround<T>,
+    /// If true, the observer is assumed to be fully adapted to the white
+    /// (D = 1)
+    pub discount_illuminant: bool,
+}
+
+impl<T> ViewingConditions<T>
+where
+    T: Real,
+{
+    pub fn new<X: Into<XYZ<T>>>(
+        white: X,
+        L_A: T,
+        Y_b: T,
+        surround: Surround<T>,
+    ) -> ViewingConditions<T> {
+        ViewingConditions {
+            white: white.into(),
+            L_A,
+            Y_b,
+            surround,
+            discount_illuminant: false,
+        }
+    }
+}
+
+/// The appearance correlates predicted by a colour appearance model
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Appearance<T>
+where
+    T: Real,
+{
+    /// Lightness
+    pub J: T,
+    /// Chroma
+    pub C: T,
+    /// Hue angle in degrees
+    pub h: T,
+    /// Colourfulness
+    pub M: T,
+    /// Saturation
+    pub s: T,
+    /// Brightness
+    pub Q: T,
+}
+
+#[derive(Copy, Clone)]
+enum Model {
+    CAM16,
+    CIECAM02,
+}
+
+#[replace_float_literals(T::from(literal).unwrap())]
+fn m_16<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    #[rustfmt::skip]
+    let m = Matrix33::<T>::new([
+         0.401288, 0.650173, -0.051461,
+        -0.250268, 1.204414,  0.045854,
+        -0.002079, 0.048952,  0.953127,
+    ]);
+    m
+}
+
+#[replace_float_literals(T::from(literal).unwrap())]
+fn m_cat02<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    #[rustfmt::skip]
+    let m = Matrix33::<T>::new([
+         0.7328, 0.4296, -0.1624,
+        -0.7036, 1.6975,  0.0061,
+         0.0030, 0.0136,  0.9834,
+    ]);
+    m
+}
+
+#[replace_float_literals(T::from(literal).unwrap())]
+fn m_hpe<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    #[rustfmt::skip]
+    let m = Matrix33::<T>::new([
+         0.38971, 0.68898, -0.07868,
+        -0.22981, 1.18340,  0.04641,
+         0.00000, 0.00000,  1.00000,
+    ]);
+    m
+}
+
+/// Inverse of [m_16]
+#[replace_float_literals(T::from(literal).unwrap())]
+fn m_16_inv<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    #[rustfmt::skip]
+    let m = Matrix33::<T>::new([
+           1.8620678550872327,  -1.0112546305316843,   0.14918677544445172,
+          0.38752654323613717,   0.6214474419314754, -0.008973985167612518,
+        -0.015841498849333856, -0.03412293802851556,    1.0499644368778493,
+    ]);
+    m
+}
+
+/// Inverse of [m_cat02]
+#[replace_float_literals(T::from(literal).unwrap())]
+fn m_cat02_inv<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    #[rustfmt::skip]
+    let m = Matrix33::<T>::new([
+           1.0961238208355142,  -0.27886900021828726,  0.1827451793827731,
+          0.45436904197535916,    0.4735331543074117, 0.07209780371722913,
+        -0.009627608738429355, -0.005698031216113421,  1.0153256399545427,
+    ]);
+    m
+}
+
+/// Inverse of [m_hpe]
+#[replace_float_literals(T::from(literal).unwrap())]
+fn m_hpe_inv<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    #[rustfmt::skip]
+    let m = Matrix33::<T>::new([
+         1.9101968340520348, -1.1121238927878747,   0.20190795676749937,
+        0.37095008824868864,  0.6290542573926132, -8.055142184358517e-6,
+                        0.0,                 0.0,                   1.0,
+    ]);
+    m
+}
+
+/// Values derived from the viewing conditions that are shared by the
+/// forward and inverse transforms
+struct Derived<T>
+where
+    T: Real,
+{
+    model: Model,
+    F_L: T,
+    n: T,
+    z: T,
+    N_bb: T,
+    N_cb: T,
+    D_RGB: XYZ<T>,
+    A_w: T,
+}
+
+impl<T> Derived<T>
+where
+    T: Real,
+{
+    #[replace_float_literals(T::from(literal).unwrap())]
+    fn new(vc: &ViewingConditions<T>, model: Model) -> Derived<T> {
+        let k = 1.0 / (5.0 * vc.L_A + 1.0);
+        let k4 = k.powi(4);
+        let F_L = 0.2 * k4 * (5.0 * vc.L_A)
+            + 0.1 * sqr(1.0 - k4) * (5.0 * vc.L_A).cbrt();
+
+        let n = vc.Y_b / vc.white.y;
+        let z = 1.48 + n.sqrt();
+        let N_bb = 0.725 * n.powf(-0.2);
+        let N_cb = N_bb;
+
+        let D = if vc.discount_illuminant {
+            1.0
+        } else {
+            clamp(
+                vc.surround.F
+                    * (1.0 - (1.0 / 3.6) * ((-vc.L_A - 42.0) / 92.0).exp()),
+                0.0,
+                1.0,
+            )
+        };
+
+        let m_cat = match model {
+            Model::CAM16 => m_16(),
+            Model::CIECAM02 => m_cat02(),
+        };
+
+        let RGB_w = m_cat * vc.white;
+        let D_RGB = XYZ::new(
+            D * vc.white.y / RGB_w.x + 1.0 - D,
+            D * vc.white.y / RGB_w.y + 1.0 - D,
+            D * vc.white.y / RGB_w.z + 1.0 - D,
+        );
+
+        let mut derived = Derived {
+            model,
+            F_L,
+            n,
+            z,
+            N_bb,
+            N_cb,
+            D_RGB,
+            A_w: 0.0,
+        };
+
+        let RGB_aw = derived.compress(derived.cat_to_hpe(RGB_w * D_RGB));
+        derived.A_w = derived.achromatic(RGB_aw);
+
+        derived
+    }
+
+    /// Convert adapted RGB to the space in which compression is applied
+    fn cat_to_hpe(&self, RGB_c: XYZ<T>) -> XYZ<T> {
+        match self.model {
+            Model::CAM16 => RGB_c,
+            Model::CIECAM02 => m_hpe() * (m_cat02_inv() * RGB_c),
+        }
+    }
+
+    /// Inverse of [Derived::cat_to_hpe]
+    fn hpe_to_cat(&self, RGB_p: XYZ<T>) -> XYZ<T> {
+        match self.model {
+            Model::CAM16 => RGB_p,
+            Model::CIECAM02 => m_cat02() * (m_hpe_inv() * RGB_p),
+        }
+    }
+
+    #[replace_float_literals(T::from(literal).unwrap())]
+    fn compress_one(&self, x: T) -> T {
+        let p = (self.F_L * x.abs() / 100.0).powf(0.42);
+        x.signum() * 400.0 * p / (p + 27.13) + 0.1
+    }
+
+    #[replace_float_literals(T::from(literal).unwrap())]
+    fn decompress_one(&self, x: T) -> T {
+        let x = x - 0.1;
+        x.signum()
+            * (100.0 / self.F_L)
+            * (27.13 * x.abs() / (400.0 - x.abs())).powf(1.0 / 0.42)
+    }
+
+    fn compress(&self, RGB: XYZ<T>) -> XYZ<T> {
+        XYZ::new(
+            self.compress_one(RGB.x),
+            self.compress_one(RGB.y),
+            self.compress_one(RGB.z),
+        )
+    }
+
+    fn decompress(&self, RGB_a: XYZ<T>) -> XYZ<T> {
+        XYZ::new(
+            self.decompress_one(RGB_a.x),
+            self.decompress_one(RGB_a.y),
+            self.decompress_one(RGB_a.z),
+        )
+    }
+
+    #[replace_float_literals(T::from(literal).unwrap())]
+    fn achromatic(&self, RGB_a: XYZ<T>) -> T {
+        (2.0 * RGB_a.x + RGB_a.y + 0.05 * RGB_a.z - 0.305) * self.N_bb
+    }
+
+    #[replace_float_literals(T::from(literal).unwrap())]
+    fn forward(&self, xyz: XYZ<T>, vc: &ViewingConditions<T>) -> Appearance<T> {
+        let m_cat = match self.model {
+            Model::CAM16 => m_16(),
+            Model::CIECAM02 => m_cat02(),
+        };
+
+        let RGB_c = (m_cat * xyz) * self.D_RGB;
+        let RGB_a = self.compress(self.cat_to_hpe(RGB_c));
+
+        let a = RGB_a.x - 12.0 * RGB_a.y / 11.0 + RGB_a.z / 11.0;
+        let b = (RGB_a.x + RGB_a.y - 2.0 * RGB_a.z) / 9.0;
+
+        let h = atan2(b, a).to_degrees();
+        let h = if h < 0.0 { h + 360.0 } else { h };
+
+        let e_t = 0.25 * ((h.to_radians() + 2.0).cos() + 3.8);
+        let A = self.achromatic(RGB_a);
+        let J = 100.0 * (A / self.A_w).powf(vc.surround.c * self.z);
+        let Q = (4.0 / vc.surround.c)
+            * (J / 100.0).sqrt()
+            * (self.A_w + 4.0)
+            * self.F_L.powf(0.25);
+
+        let t =
+            (50000.0 / 13.0 * vc.surround.N_c * self.N_cb * e_t * hypot(a, b))
+                / (RGB_a.x + RGB_a.y + 21.0 / 20.0 * RGB_a.z);
+        let C = t.powf(0.9)
+            * (J / 100.0).sqrt()
+            * (1.64 - 0.29.powf(self.n)).powf(0.73);
+        let M = C * self.F_L.powf(0.25);
+        let s = 100.0 * (M / Q).sqrt();
+
+        Appearance { J, C, h, M, s, Q }
+    }
+
+    #[replace_float_literals(T::from(literal).unwrap())]
+    fn inverse(&self, J: T, C: T, h: T, vc: &ViewingConditions<T>) -> XYZ<T> {
+        let t = (C
+            / ((J / 100.0).sqrt() * (1.64 - 0.29.powf(self.n)).powf(0.73)))
+        .powf(1.0 / 0.9);
+
+        let h_r = h.to_radians();
+        let e_t = 0.25 * ((h_r + 2.0).cos() + 3.8);
+        let A = self.A_w * (J / 100.0).powf(1.0 / (vc.surround.c * self.z));
+
+        let p_2 = A / self.N_bb + 0.305;
+        let p_3 = 21.0 / 20.0;
+        let (sin_h, cos_h) = (h_r.sin(), h_r.cos());
+
+        let (a, b) = if t == 0.0 {
+            (0.0, 0.0)
+        } else {
+            let p_1 = (50000.0 / 13.0) * vc.surround.N_c * self.N_cb * e_t / t;
+            if sin_h.abs() >= cos_h.abs() {
+                let p_4 = p_1 / sin_h;
+                let b = p_2 * (2.0 + p_3) * (460.0 / 1403.0)
+                    / (p_4 + (2.0 + p_3) * (220.0 / 1403.0) * (cos_h / sin_h)
+                        - 27.0 / 1403.0
+                        + p_3 * (6300.0 / 1403.0));
+                (b * cos_h / sin_h, b)
+            } else {
+                let p_5 = p_1 / cos_h;
+                let a = p_2 * (2.0 + p_3) * (460.0 / 1403.0)
+                    / (p_5 + (2.0 + p_3) * (220.0 / 1403.0)
+                        - (27.0 / 1403.0 - p_3 * (6300.0 / 1403.0))
+                            * (sin_h / cos_h));
+                (a, a * sin_h / cos_h)
+            }
+        };
+
+        let RGB_a = XYZ::new(
+            (460.0 * p_2 + 451.0 * a + 288.0 * b) / 1403.0,
+            (460.0 * p_2 - 891.0 * a - 261.0 * b) / 1403.0,
+            (460.0 * p_2 - 220.0 * a - 6300.0 * b) / 1403.0,
+        );
+
+        let RGB_c = self.hpe_to_cat(self.decompress(RGB_a));
+
+        let m_cat_inv = match self.model {
+            Model::CAM16 => m_16_inv(),
+            Model::CIECAM02 => m_cat02_inv(),
+        };
+
+        m_cat_inv * (RGB_c / self.D_RGB)
+    }
+}
+
+/// Compute the CAM16 appearance correlates of `xyz` viewed under the
+/// conditions `vc`
+pub fn xyz_to_cam16<T, X: Into<XYZ<T>>>(
+    xyz: X,
+    vc: &ViewingConditions<T>,
+) -> Appearance<T>
+where
+    T: Real,
+{
+    Derived::new(vc, Model::CAM16).forward(xyz.into(), vc)
+}
+
+/// Compute the XYZ of the stimulus that has the CAM16 lightness `J`, chroma
+/// `C` and hue angle `h` when viewed under the conditions `vc`
+pub fn cam16_to_xyz<T>(J: T, C: T, h: T, vc: &ViewingConditions<T>) -> XYZ<T>
+where
+    T: Real,
+{
+    Derived::new(vc, Model::CAM16).inverse(J, C, h, vc)
+}
+
+/// Compute the CIECAM02 appearance correlates of `xyz` viewed under the
+/// conditions `vc`
+pub fn xyz_to_ciecam02<T, X: Into<XYZ<T>>>(
+    xyz: X,
+    vc: &ViewingConditions<T>,
+) -> Appearance<T>
+where
+    T: Real,
+{
+    Derived::new(vc, Model::CIECAM02).forward(xyz.into(), vc)
+}
+
+/// Compute the XYZ of the stimulus that has the CIECAM02 lightness `J`,
+/// chroma `C` and hue angle `h` when viewed under the conditions `vc`
+pub fn ciecam02_to_xyz<T>(J: T, C: T, h: T, vc: &ViewingConditions<T>) -> XYZ<T>
+where
+    T: Real,
+{
+    Derived::new(vc, Model::CIECAM02).inverse(J, C, h, vc)
+}
+
+/// Parameters of the uniform colour spaces derived from CAM16 by Li et al.
+/// (2017), following Luo et al. (2006)
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UniformSpace<T>
+where
+    T: Real,
+{
+    pub K_L: T,
+    pub c_1: T,
+    pub c_2: T,
+}
+
+impl<T> UniformSpace<T>
+where
+    T: Real,
+{
+    /// CAM16-UCS, for general use
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn ucs() -> UniformSpace<T> {
+        UniformSpace {
+            K_L: 1.0,
+            c_1: 0.007,
+            c_2: 0.0228,
+        }
+    }
+
+    /// CAM16-LCD, for large colour differences
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn lcd() -> UniformSpace<T> {
+        UniformSpace {
+            K_L: 0.77,
+            c_1: 0.007,
+            c_2: 0.0053,
+        }
+    }
+
+    /// CAM16-SCD, for small colour differences
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn scd() -> UniformSpace<T> {
+        UniformSpace {
+            K_L: 1.24,
+            c_1: 0.007,
+            c_2: 0.0363,
+        }
+    }
+}
+
+/// A colour in one of the CAM16 uniform colour spaces: J'a'b'
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Jab<T>
+where
+    T: Real,
+{
+    pub J: T,
+    pub a: T,
+    pub b: T,
+}
+
+/// Convert the lightness `J`, colourfulness `M` and hue angle `h`
+/// appearance correlates to J'a'b' in the uniform colour space `space`
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn jmh_to_jab<T>(J: T, M: T, h: T, space: &UniformSpace<T>) -> Jab<T>
+where
+    T: Real,
+{
+    let J_p = (1.0 + 100.0 * space.c_1) * J / (1.0 + space.c_1 * J);
+    let M_p = (1.0 + space.c_2 * M).ln() / space.c_2;
+    let h = h.to_radians();
+
+    Jab {
+        J: J_p,
+        a: M_p * h.cos(),
+        b: M_p * h.sin(),
+    }
+}
+
+/// Convert J'a'b' in the uniform colour space `space` back to a tuple of the
+/// lightness, colourfulness and hue angle appearance correlates `(J, M, h)`
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn jab_to_jmh<T>(c: Jab<T>, space: &UniformSpace<T>) -> (T, T, T)
+where
+    T: Real,
+{
+    let J = c.J / (1.0 - space.c_1 * (c.J - 100.0));
+    let M = ((space.c_2 * hypot(c.a, c.b)).exp() - 1.0) / space.c_2;
+    let h = atan2(c.b, c.a).to_degrees();
+    let h = if h < 0.0 { h + 360.0 } else { h };
+
+    (J, M, h)
+}
+
+/// Convert `xyz` viewed under the conditions `vc` to J'a'b' in the
+/// CAM16-based uniform colour space `space`
+pub fn xyz_to_cam16_jab<T, X: Into<XYZ<T>>>(
+    xyz: X,
+    vc: &ViewingConditions<T>,
+    space: &UniformSpace<T>,
+) -> Jab<T>
+where
+    T: Real,
+{
+    let a = xyz_to_cam16(xyz, vc);
+    jmh_to_jab(a.J, a.M, a.h, space)
+}
+
+/// Convert J'a'b' in the CAM16-based uniform colour space `space` to the XYZ
+/// of the stimulus that has that appearance under the conditions `vc`
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn cam16_jab_to_xyz<T>(
+    c: Jab<T>,
+    vc: &ViewingConditions<T>,
+    space: &UniformSpace<T>,
+) -> XYZ<T>
+where
+    T: Real,
+{
+    let (J, M, h) = jab_to_jmh(c, space);
+    let derived = Derived::new(vc, Model::CAM16);
+    let C = M / derived.F_L.powf(0.25);
+    derived.inverse(J, C, h, vc)
+}
+
+/// Compute the difference between two colours in the same CAM16-based
+/// uniform colour space `space`
+pub fn delta_E_cam16<T>(c1: Jab<T>, c2: Jab<T>, space: &UniformSpace<T>) -> T
+where
+    T: Real,
+{
+    (sqr((c1.J - c2.J) / space.K_L) + sqr(c1.a - c2.a) + sqr(c1.b - c2.b))
+        .sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vc() -> ViewingConditions<f64> {
+        ViewingConditions::new(
+            xyz(95.05, 100.0, 108.88),
+            318.31,
+            20.0,
+            Surround::average(),
+        )
+    }
+
+    fn check(a: Appearance<f64>, b: Appearance<f64>) {
+        assert!((a.J - b.J).abs() < 1e-5, "{:?} != {:?}", a, b);
+        assert!((a.C - b.C).abs() < 1e-5, "{:?} != {:?}", a, b);
+        assert!((a.h - b.h).abs() < 1e-5, "{:?} != {:?}", a, b);
+        assert!((a.M - b.M).abs() < 1e-5, "{:?} != {:?}", a, b);
+        assert!((a.s - b.s).abs() < 1e-5, "{:?} != {:?}", a, b);
+        assert!((a.Q - b.Q).abs() < 1e-5, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn inverse_matrices() {
+        let i = M3f64::make_identity();
+        for m in [
+            m_16::<f64>() * m_16_inv(),
+            m_cat02::<f64>() * m_cat02_inv(),
+            m_hpe::<f64>() * m_hpe_inv(),
+        ]
+        .iter()
+        {
+            for (a, b) in m.x.iter().zip(i.x.iter()) {
+                assert!((a - b).abs() < 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn cam16() {
+        let vc = vc();
+        let c = xyz(19.01, 20.0, 21.78);
+        let a = xyz_to_cam16(c, &vc);
+        check(
+            a,
+            Appearance {
+                J: 41.73120791,
+                C: 0.10335574,
+                h: 217.06795977,
+                M: 0.10743677,
+                s: 2.34501507,
+                Q: 195.37170899,
+            },
+        );
+
+        let c_2 = cam16_to_xyz(a.J, a.C, a.h, &vc);
+        assert!((c - c_2).abs().x < 1e-10);
+        assert!((c - c_2).abs().y < 1e-10);
+        assert!((c - c_2).abs().z < 1e-10);
+    }
+
+    #[test]
+    fn ciecam02() {
+        let vc = vc();
+        let c = xyz(19.01, 20.0, 21.78);
+        let a = xyz_to_ciecam02(c, &vc);
+        check(
+            a,
+            Appearance {
+                J: 41.73109113,
+                C: 0.10470776,
+                h: 219.04843266,
+                M: 0.10884217,
+                s: 2.36030533,
+                Q: 195.37132952,
+            },
+        );
+
+        let c_2 = ciecam02_to_xyz(a.J, a.C, a.h, &vc);
+        assert!((c - c_2).abs().x < 1e-10);
+        assert!((c - c_2).abs().y < 1e-10);
+        assert!((c - c_2).abs().z < 1e-10);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut vc = vc();
+        vc.surround = Surround::dim();
+        vc.L_A = 64.0;
+        for c in crate::colorchecker::XYZ_D65.values() {
+            let c = *c * 100.0;
+            let a = xyz_to_cam16(c, &vc);
+            let c_2 = cam16_to_xyz(a.J, a.C, a.h, &vc);
+            assert!((c - c_2).abs().x < 1e-9, "{:?} != {:?}", c, c_2);
+            assert!((c - c_2).abs().y < 1e-9, "{:?} != {:?}", c, c_2);
+            assert!((c - c_2).abs().z < 1e-9, "{:?} != {:?}", c, c_2);
+
+            let a = xyz_to_ciecam02(c, &vc);
+            let c_2 = ciecam02_to_xyz(a.J, a.C, a.h, &vc);
+            assert!((c - c_2).abs().x < 1e-9, "{:?} != {:?}", c, c_2);
+            assert!((c - c_2).abs().y < 1e-9, "{:?} != {:?}", c, c_2);
+            assert!((c - c_2).abs().z < 1e-9, "{:?} != {:?}", c, c_2);
+        }
+    }
+
+    #[test]
+    fn cam16_jab() {
+        let c = jmh_to_jab(
+            41.73109113,
+            0.10884217,
+            219.04843266,
+            &UniformSpace::ucs(),
+        );
+        assert!((c.J - 54.90433134).abs() < 1e-6, "{:?}", c);
+
+        let (J, M, h) = jab_to_jmh(c, &UniformSpace::ucs());
+        assert!((J - 41.73109113).abs() < 1e-10);
+        assert!((M - 0.10884217).abs() < 1e-10);
+        assert!((h - 219.04843266).abs() < 1e-10);
+
+        let vc = vc();
+        for space in [
+            UniformSpace::ucs(),
+            UniformSpace::lcd(),
+            UniformSpace::scd(),
+        ]
+        .iter()
+        {
+            for c in crate::colorchecker::XYZ_D65.values() {
+                let c = *c * 100.0;
+                let jab = xyz_to_cam16_jab(c, &vc, space);
+                let c_2 = cam16_jab_to_xyz(jab, &vc, space);
+                assert!((c - c_2).abs().x < 1e-9, "{:?} != {:?}", c, c_2);
+                assert!((c - c_2).abs().y < 1e-9, "{:?} != {:?}", c, c_2);
+                assert!((c - c_2).abs().z < 1e-9, "{:?} != {:?}", c, c_2);
+            }
+        }
+    }
+
+    #[test]
+    fn delta_e_cam16() {
+        let space = UniformSpace::lcd();
+        let c1 = Jab {
+            J: 50.0,
+            a: 3.0,
+            b: -4.0,
+        };
+        let c2 = Jab {
+            J: 50.0 + 0.77 * 12.0,
+            a: 0.0,
+            b: 0.0,
+        };
+        assert!((delta_E_cam16(c1, c2, &space) - 13.0).abs() < 1e-12);
+        assert_eq!(
+            delta_E_cam16(c1, c2, &space),
+            delta_E_cam16(c2, c1, &space)
+        );
+    }
+}
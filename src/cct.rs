@@ -0,0 +1,279 @@
+//! Correlated colour temperature (CCT) and Duv estimation.
+//!
+//! CCT is the temperature of the Planckian radiator whose chromaticity is
+//! nearest to that of a given stimulus in the CIE 1960 UCS, and Duv is the
+//! signed distance from the Planckian locus, positive above the locus (i.e.
+//! towards green) and negative below it (towards magenta). All methods use
+//! the CIE 1931 2-degree standard observer.
+use crate::chromaticity::{xy, XYY};
+use crate::cmf::CIE_1931_2_DEGREE;
+use crate::illuminant::spd::planck;
+use crate::math::hypot;
+
+/// Temperature range searched by [xy_to_cct_ohno2013]
+const OHNO_T_MIN: f64 = 1000.0;
+const OHNO_T_MAX: f64 = 100_000.0;
+
+/// Convert xy chromaticity to CIE 1960 UCS uv
+fn xy_to_uv(c: XYY<f64>) -> (f64, f64) {
+    let d = -2.0 * c.x + 12.0 * c.y + 3.0;
+    (4.0 * c.x / d, 6.0 * c.y / d)
+}
+
+/// Convert CIE 1960 UCS uv to xy chromaticity
+fn uv_to_xy(u: f64, v: f64) -> XYY<f64> {
+    let d = 2.0 * u - 8.0 * v + 4.0;
+    xy(3.0 * u / d, 2.0 * v / d)
+}
+
+/// Returns the CIE 1960 UCS uv coordinates of the Planckian radiator at
+/// temperature `t`, in Kelvin
+fn planckian_uv(t: f64) -> (f64, f64) {
+    let cmf = &*CIE_1931_2_DEGREE;
+    let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+    for ((xb, yb), zb) in
+        cmf.x_bar.iter().zip(cmf.y_bar.iter()).zip(cmf.z_bar.iter())
+    {
+        let p = planck(xb.nm, t);
+        x += p * xb.v;
+        y += p * yb.v;
+        z += p * zb.v;
+    }
+
+    let d = x + 15.0 * y + 3.0 * z;
+    (4.0 * x / d, 6.0 * y / d)
+}
+
+/// Returns the correlated colour temperature, in Kelvin, and Duv of the
+/// given chromaticity using the method of Ohno (2013). The nearest point on
+/// the Planckian locus is found by a cascade of successively finer Planck
+/// tables followed by Ohno's triangular solution, giving a CCT accurate to
+/// well under 1K between 1000K and 100000K.
+///
+/// Returns a tuple of `(cct, duv)`.
+///
+/// Ohno, Y. (2014). Practical Use and Calculation of CCT and Duv. LEUKOS,
+/// 10(1), 47–55.
+pub fn xy_to_cct_ohno2013<C: Into<XYY<f64>>>(c: C) -> (f64, f64) {
+    const N: usize = 15;
+
+    let (u, v) = xy_to_uv(c.into());
+
+    let mut t_min = OHNO_T_MIN;
+    let mut t_max = OHNO_T_MAX;
+    let mut table = [(0.0, 0.0); N];
+    let mut temps = [0.0; N];
+    let mut i = 0;
+
+    while t_max / t_min - 1.0 > 1e-7 {
+        let step = (t_max / t_min).powf(1.0 / (N - 1) as f64);
+        for j in 0..N {
+            temps[j] = t_min * step.powi(j as i32);
+            table[j] = planckian_uv(temps[j]);
+        }
+
+        i = (0..N)
+            .map(|j| hypot(u - table[j].0, v - table[j].1))
+            .enumerate()
+            .fold((0, f64::MAX), |m, (j, d)| if d < m.1 { (j, d) } else { m })
+            .0;
+
+        if i == 0 || i == N - 1 {
+            // nearest point is at the boundary of the searched range
+            break;
+        }
+
+        t_min = temps[i - 1];
+        t_max = temps[i + 1];
+    }
+
+    let t = if i == 0 || i == N - 1 {
+        temps[i]
+    } else {
+        // triangular solution
+        let (up, vp) = table[i - 1];
+        let (un, vn) = table[i + 1];
+        let dp = hypot(u - up, v - vp);
+        let dn = hypot(u - un, v - vn);
+        let l = hypot(un - up, vn - vp);
+        let x = (dp * dp - dn * dn + l * l) / (2.0 * l);
+        temps[i - 1] + (temps[i + 1] - temps[i - 1]) * x / l
+    };
+
+    let (ut, vt) = planckian_uv(t);
+    let duv = hypot(u - ut, v - vt) * (v - vt).signum();
+
+    (t, duv)
+}
+
+/// Robertson's isotemperature lines: (reciprocal megakelvin, u, v, slope)
+const ROBERTSON: [(f64, f64, f64, f64); 31] = [
+    (0.0, 0.18006, 0.26352, -0.24341),
+    (10.0, 0.18066, 0.26589, -0.25479),
+    (20.0, 0.18133, 0.26846, -0.26876),
+    (30.0, 0.18208, 0.27119, -0.28539),
+    (40.0, 0.18293, 0.27407, -0.30470),
+    (50.0, 0.18388, 0.27709, -0.32675),
+    (60.0, 0.18494, 0.28021, -0.35156),
+    (70.0, 0.18611, 0.28342, -0.37915),
+    (80.0, 0.18740, 0.28668, -0.40955),
+    (90.0, 0.18880, 0.28997, -0.44278),
+    (100.0, 0.19032, 0.29326, -0.47888),
+    (125.0, 0.19462, 0.30141, -0.58204),
+    (150.0, 0.19962, 0.30921, -0.70471),
+    (175.0, 0.20525, 0.31647, -0.84901),
+    (200.0, 0.21142, 0.32312, -1.0182),
+    (225.0, 0.21807, 0.32909, -1.2168),
+    (250.0, 0.22511, 0.33439, -1.4512),
+    (275.0, 0.23247, 0.33904, -1.7298),
+    (300.0, 0.24010, 0.34308, -2.0637),
+    (325.0, 0.24792, 0.34655, -2.4681),
+    (350.0, 0.25591, 0.34951, -2.9641),
+    (375.0, 0.26400, 0.35200, -3.5814),
+    (400.0, 0.27218, 0.35407, -4.3633),
+    (425.0, 0.28039, 0.35577, -5.3762),
+    (450.0, 0.28863, 0.35714, -6.7262),
+    (475.0, 0.29685, 0.35823, -8.5955),
+    (500.0, 0.30505, 0.35907, -11.324),
+    (525.0, 0.31320, 0.35968, -15.628),
+    (550.0, 0.32129, 0.36011, -23.325),
+    (575.0, 0.32931, 0.36038, -40.770),
+    (600.0, 0.33724, 0.36051, -116.45),
+];
+
+/// Returns the correlated colour temperature, in Kelvin, and Duv of the
+/// given chromaticity by interpolating between Robertson's (1968)
+/// isotemperature lines. This is faster than [xy_to_cct_ohno2013] but only
+/// accurate to a few Kelvin.
+///
+/// Returns a tuple of `(cct, duv)`, or `None` if the chromaticity lies
+/// outside the range covered by the table, i.e. below 1667K or beyond the
+/// line for infinite temperature, rather than extrapolating from the end
+/// lines.
+pub fn xy_to_cct_robertson<C: Into<XYY<f64>>>(c: C) -> Option<(f64, f64)> {
+    let (u, v) = xy_to_uv(c.into());
+
+    // signed distance of (u, v) from the isotemperature line at index i
+    let dist = |i: usize| {
+        let (_, ui, vi, ti) = ROBERTSON[i];
+        ((v - vi) - ti * (u - ui)) / (1.0 + ti * ti).sqrt()
+    };
+
+    // find the pair of isotemperature lines either side of (u, v)
+    let d0 = dist(0);
+    let mut i = 1;
+    while i < ROBERTSON.len() - 1 && dist(i) * d0 > 0.0 {
+        i += 1;
+    }
+
+    let dm = dist(i - 1);
+    let di = dist(i);
+    let f = dm / (dm - di);
+    if !(0.0..=1.0).contains(&f) {
+        return None;
+    }
+
+    let (mp, up, vp, _) = ROBERTSON[i - 1];
+    let (mi, ui, vi, _) = ROBERTSON[i];
+
+    let cct = 1.0e6 / (mp + f * (mi - mp));
+
+    let ut = up + f * (ui - up);
+    let vt = vp + f * (vi - vp);
+    let duv = hypot(u - ut, v - vt) * (v - vt).signum();
+
+    Some((cct, duv))
+}
+
+/// Returns the correlated colour temperature, in Kelvin, of the given
+/// chromaticity using McCamy's (1992) cubic approximation. This is very fast
+/// but is only accurate to within a few Kelvin for chromaticities close to
+/// the Planckian locus between roughly 2856K and 6504K, and does not
+/// provide Duv.
+pub fn xy_to_cct_mccamy<C: Into<XYY<f64>>>(c: C) -> f64 {
+    let c = c.into();
+    let n = (c.x - 0.3320) / (0.1858 - c.y);
+    449.0 * n * n * n + 3525.0 * n * n + 6823.3 * n + 5520.33
+}
+
+/// Returns the xy chromaticity with the given correlated colour temperature,
+/// `cct`, in Kelvin, and distance `duv` from the Planckian locus. This is
+/// the inverse of [xy_to_cct_ohno2013].
+pub fn cct_to_xy(cct: f64, duv: f64) -> XYY<f64> {
+    let (u0, v0) = planckian_uv(cct);
+    let (u1, v1) = planckian_uv(cct - 0.01);
+
+    // the tangent to the locus in the direction of decreasing temperature
+    // rotated by 90 degrees gives the normal pointing towards +v
+    let du = u1 - u0;
+    let dv = v1 - v0;
+    let l = hypot(du, dv);
+
+    uv_to_xy(u0 - duv * dv / l, v0 + duv * du / l)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::illuminant;
+
+    #[test]
+    fn ohno2013() {
+        let (t, duv) = xy_to_cct_ohno2013(illuminant::xy::D65);
+        assert!((t - 6504.0).abs() < 1.0, "{}", t);
+        assert!((duv - 0.0032).abs() < 1e-4, "{}", duv);
+
+        let (t, duv) = xy_to_cct_ohno2013(illuminant::xy::A);
+        assert!((t - 2856.0).abs() < 1.0, "{}", t);
+        assert!(duv.abs() < 1e-4, "{}", duv);
+
+        for &(cct, duv) in [
+            (1500.0, 0.0),
+            (2700.0, 0.02),
+            (5000.0, -0.01),
+            (6500.0, 0.003),
+            (20000.0, -0.02),
+        ]
+        .iter()
+        {
+            let (t, d) = xy_to_cct_ohno2013(cct_to_xy(cct, duv));
+            assert!((t - cct).abs() / cct < 1e-5, "{} != {}", t, cct);
+            assert!((d - duv).abs() < 1e-6, "{} != {}", d, duv);
+        }
+    }
+
+    #[test]
+    fn robertson() {
+        // the table should sit on our locus
+        for &(m, u, v, _) in ROBERTSON.iter().skip(1) {
+            let (ut, vt) = planckian_uv(1.0e6 / m);
+            assert!(hypot(u - ut, v - vt) < 1e-4, "{}", m);
+        }
+
+        for &(cct, duv) in [
+            (2000.0, 0.0),
+            (2856.0, 0.01),
+            (6500.0, -0.01),
+            (15000.0, 0.0),
+        ]
+        .iter()
+        {
+            let (t, d) = xy_to_cct_robertson(cct_to_xy(cct, duv)).unwrap();
+            assert!((t - cct).abs() / cct < 1e-3, "{} != {}", t, cct);
+            assert!((d - duv).abs() < 2e-4, "{} != {}", d, duv);
+        }
+
+        // outside the table at either end
+        assert!(xy_to_cct_robertson(cct_to_xy(1500.0, 0.0)).is_none());
+        assert!(xy_to_cct_robertson(cct_to_xy(1000.0, 0.0)).is_none());
+        assert!(xy_to_cct_robertson(xy(0.2, 0.2)).is_none());
+    }
+
+    #[test]
+    fn mccamy() {
+        let t = xy_to_cct_mccamy(illuminant::xy::D65);
+        assert!((t - 6504.0).abs() < 5.0, "{}", t);
+        let t = xy_to_cct_mccamy(illuminant::xy::A);
+        assert!((t - 2856.0).abs() < 5.0, "{}", t);
+    }
+}
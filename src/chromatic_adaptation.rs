@@ -148,3 +148,49 @@ where
 
     M_A_inv * M_wp * M_A
 }
+
+/// Compute the CAT16 chromatic adaptation transform matrix.
+/// XYZ colors are specified relative to a reference illuminant. The
+/// chromatic adaptation transform allows to adapt from one illuminant
+/// to another.
+/// See Li et al., "Comprehensive color solutions: CAM16, CAT16, and CAM16-UCS"
+/// (2017) for more information.
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn cat16<T, X1: Into<XYZ<T>>, X2: Into<XYZ<T>>>(
+    wp_src: X1,
+    wp_dst: X2,
+) -> Matrix33<T>
+where
+    T: Real,
+{
+    let wp_src: XYZ<T> = wp_src.into();
+    let wp_dst: XYZ<T> = wp_dst.into();
+    if wp_src == wp_dst {
+        return Matrix33::<T>::make_identity();
+    }
+
+    #[rustfmt::skip]
+    let M_A = Matrix33::<T>::new([
+         0.401288, 0.650173, -0.051461,
+        -0.250268, 1.204414,  0.045854,
+        -0.002079, 0.048952,  0.953127,
+    ]);
+    let M_A_inv = M_A.inverse().unwrap();
+
+    let wp_src_A = M_A * wp_src;
+    let wp_dst_A = M_A * wp_dst;
+
+    let M_wp = Matrix33::new([
+        wp_dst_A.x / wp_src_A.x,
+        0.0,
+        0.0,
+        0.0,
+        wp_dst_A.y / wp_src_A.y,
+        0.0,
+        0.0,
+        0.0,
+        wp_dst_A.z / wp_src_A.z,
+    ]);
+
+    M_A_inv * M_wp * M_A
+}
@@ -2,7 +2,10 @@
 use super::xyz::XYZ;
 use std::convert::From;
 
-use crate::math::Real;
+use crate::cmf::CMF;
+use crate::math::{hypot, Real};
+
+use itertools::izip;
 
 pub type XYYf32 = XYY<f32>;
 pub type XYYf64 = XYY<f64>;
@@ -56,3 +59,426 @@ where
         XYY::<T>::from_xyz(c)
     }
 }
+
+impl From<XYYf64> for XYYf32 {
+    fn from(c: XYYf64) -> XYYf32 {
+        XYYf32 {
+            x: c.x as f32,
+            y: c.y as f32,
+            Y: c.Y as f32,
+        }
+    }
+}
+
+pub type UVf32 = UV<f32>;
+pub type UVf64 = UV<f64>;
+
+/// Defines a pair of CIE 1976 UCS `u'v'` chromaticity coordinates
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct UV<T>
+where
+    T: Real,
+{
+    pub u: T,
+    pub v: T,
+}
+
+pub fn uv<T>(u: T, v: T) -> UV<T>
+where
+    T: Real,
+{
+    UV::new(u, v)
+}
+
+impl<T> UV<T>
+where
+    T: Real,
+{
+    pub fn new(u: T, v: T) -> UV<T> {
+        UV::<T> { u, v }
+    }
+
+    /// Convert the given XYZ tristimulus value to a `u'v'` chromaticity
+    /// value
+    pub fn from_xyz(c: XYZ<T>) -> UV<T> {
+        let d =
+            c.x + T::from(15.0).unwrap() * c.y + T::from(3.0).unwrap() * c.z;
+        UV::<T> {
+            u: T::from(4.0).unwrap() * c.x / d,
+            v: T::from(9.0).unwrap() * c.y / d,
+        }
+    }
+
+    /// Convert the given `xy` chromaticity value to `u'v'`
+    pub fn from_xy(c: XYY<T>) -> UV<T> {
+        let d = T::from(-2.0).unwrap() * c.x
+            + T::from(12.0).unwrap() * c.y
+            + T::from(3.0).unwrap();
+        UV::<T> {
+            u: T::from(4.0).unwrap() * c.x / d,
+            v: T::from(9.0).unwrap() * c.y / d,
+        }
+    }
+
+    /// Convert this `u'v'` chromaticity to an `xy` chromaticity with unit
+    /// luminance
+    pub fn to_xy(&self) -> XYY<T> {
+        let d = T::from(6.0).unwrap() * self.u
+            - T::from(16.0).unwrap() * self.v
+            + T::from(12.0).unwrap();
+        xy(
+            T::from(9.0).unwrap() * self.u / d,
+            T::from(4.0).unwrap() * self.v / d,
+        )
+    }
+}
+
+impl<T> From<XYZ<T>> for UV<T>
+where
+    T: Real,
+{
+    fn from(c: XYZ<T>) -> UV<T> {
+        UV::<T>::from_xyz(c)
+    }
+}
+
+impl<T> From<XYY<T>> for UV<T>
+where
+    T: Real,
+{
+    fn from(c: XYY<T>) -> UV<T> {
+        UV::<T>::from_xy(c)
+    }
+}
+
+impl<T> From<UV<T>> for XYY<T>
+where
+    T: Real,
+{
+    fn from(c: UV<T>) -> XYY<T> {
+        c.to_xy()
+    }
+}
+
+#[test]
+fn test_uv_conversions() {
+    let d65 = crate::illuminant::xy::D65;
+    let c: UV<f64> = d65.into();
+    assert!((c.u - 0.19783).abs() < 1e-5);
+    assert!((c.v - 0.46832).abs() < 1e-5);
+
+    let c2: UV<f64> = XYZ::from(d65).into();
+    assert!((c.u - c2.u).abs() < 1e-14);
+    assert!((c.v - c2.v).abs() < 1e-14);
+
+    let d65_2: XYY<f64> = c.into();
+    assert!((d65.x - d65_2.x).abs() < 1e-14);
+    assert!((d65.y - d65_2.y).abs() < 1e-14);
+}
+
+/// The spectral locus of a set of color matching functions in `xy`, i.e. the
+/// chromaticities of monochromatic stimuli, closed by the line of purples
+/// between its two ends.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpectralLocus {
+    wavelengths: Vec<f64>,
+    points: Vec<XYY<f64>>,
+    hull: Vec<XYY<f64>>,
+}
+
+/// The result of [SpectralLocus::dominant_wavelength]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DominantWavelength {
+    /// The dominant wavelength in nm, or the complementary wavelength if
+    /// `complementary` is true
+    pub wavelength: f64,
+    /// True if the color lies towards the line of purples, in which case it
+    /// has no dominant wavelength and `wavelength` is the complementary
+    /// wavelength instead
+    pub complementary: bool,
+    /// Where the line from the white point through the color meets the
+    /// boundary of the locus, either the spectral locus or the line of
+    /// purples
+    pub boundary: XYY<f64>,
+}
+
+impl SpectralLocus {
+    /// Compute the spectral locus of `cmf` at each of its wavelengths.
+    /// Wavelengths where all three functions are zero are skipped.
+    pub fn new(cmf: &CMF) -> SpectralLocus {
+        let mut wavelengths = Vec::new();
+        let mut points = Vec::new();
+        for (x, y, z) in
+            izip!(cmf.x_bar.iter(), cmf.y_bar.iter(), cmf.z_bar.iter())
+        {
+            let sum = x.v + y.v + z.v;
+            if sum > 0.0 {
+                wavelengths.push(x.nm);
+                points.push(xy(x.v / sum, y.v / sum));
+            }
+        }
+        let hull = convex_hull(&points);
+        SpectralLocus {
+            wavelengths,
+            points,
+            hull,
+        }
+    }
+
+    /// Get the wavelengths of the locus points, in nm
+    pub fn wavelengths(&self) -> &[f64] {
+        &self.wavelengths
+    }
+
+    /// Get the `xy` chromaticities of the locus points
+    pub fn points(&self) -> &[XYY<f64>] {
+        &self.points
+    }
+
+    /// Returns true if `c` lies inside the area bounded by the spectral locus
+    /// and the line of purples, i.e. it is the chromaticity of a physically
+    /// realizable color.
+    ///
+    /// The test is made against the convex hull of the locus points, since at
+    /// the ends of the tabulated range the locus can fold back on itself,
+    /// which would otherwise leave spurious holes along its edge.
+    pub fn contains<C: Into<XYY<f64>>>(&self, c: C) -> bool {
+        let c = c.into();
+        let n = self.hull.len();
+        let mut inside = false;
+        for i in 0..n {
+            let a = self.hull[i];
+            let b = self.hull[(i + 1) % n];
+            if (a.y > c.y) != (b.y > c.y)
+                && c.x < a.x + (c.y - a.y) / (b.y - a.y) * (b.x - a.x)
+            {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+
+    /// Find where the ray from `white` in direction `d` leaves the locus.
+    /// Returns the boundary point, the interpolated wavelength and whether the
+    /// ray hits the line of purples.
+    fn intersect(
+        &self,
+        white: XYY<f64>,
+        d: (f64, f64),
+    ) -> Option<(XYY<f64>, f64, bool)> {
+        let n = self.points.len();
+        let mut best: Option<(f64, XYY<f64>, f64, bool)> = None;
+        for i in 0..n {
+            let a = self.points[i];
+            let b = self.points[(i + 1) % n];
+            let e = (b.x - a.x, b.y - a.y);
+            let denom = d.0 * e.1 - d.1 * e.0;
+            if denom == 0.0 {
+                continue;
+            }
+            let w = (a.x - white.x, a.y - white.y);
+            // distance along the ray and fraction along the segment
+            let t = (w.0 * e.1 - w.1 * e.0) / denom;
+            let s = (w.0 * d.1 - w.1 * d.0) / denom;
+            if t <= 0.0 || !(0.0..=1.0).contains(&s) {
+                continue;
+            }
+            if let Some((t_best, _, _, _)) = best {
+                if t >= t_best {
+                    continue;
+                }
+            }
+            let purple = i == n - 1;
+            let nm = self.wavelengths[i]
+                + s * (self.wavelengths[(i + 1) % n] - self.wavelengths[i]);
+            best =
+                Some((t, xy(white.x + t * d.0, white.y + t * d.1), nm, purple));
+        }
+        best.map(|(_, p, nm, purple)| (p, nm, purple))
+    }
+
+    /// Compute the dominant wavelength of `c` relative to the white point
+    /// `white`. If the line from `white` through `c` meets the line of
+    /// purples rather than the spectral locus, the complementary wavelength is
+    /// returned instead, and flagged as such.
+    ///
+    /// Returns `None` if `c` is the white point.
+    ///
+    /// ```
+    /// use colorspace::*;
+    /// let locus = SpectralLocus::new(&cmf::CIE_1931_2_DEGREE);
+    /// let d = locus
+    ///     .dominant_wavelength(xy(0.5, 0.4), illuminant::xy::D65)
+    ///     .unwrap();
+    /// assert!(!d.complementary);
+    /// assert!((d.wavelength - 589.5).abs() < 0.1);
+    /// ```
+    pub fn dominant_wavelength<C, W>(
+        &self,
+        c: C,
+        white: W,
+    ) -> Option<DominantWavelength>
+    where
+        C: Into<XYY<f64>>,
+        W: Into<XYY<f64>>,
+    {
+        let c = c.into();
+        let white = white.into();
+        let d = (c.x - white.x, c.y - white.y);
+        if d.0 == 0.0 && d.1 == 0.0 {
+            return None;
+        }
+
+        let (boundary, wavelength, purple) = self.intersect(white, d)?;
+        if !purple {
+            return Some(DominantWavelength {
+                wavelength,
+                complementary: false,
+                boundary,
+            });
+        }
+
+        let (_, wavelength, _) = self.intersect(white, (-d.0, -d.1))?;
+        Some(DominantWavelength {
+            wavelength,
+            complementary: true,
+            boundary,
+        })
+    }
+
+    /// Compute the excitation purity of `c` relative to the white point
+    /// `white`: the distance from `white` to `c` as a fraction of the distance
+    /// from `white` to the boundary of the locus in the same direction.
+    ///
+    /// Returns 0 if `c` is the white point.
+    pub fn excitation_purity<C, W>(&self, c: C, white: W) -> f64
+    where
+        C: Into<XYY<f64>>,
+        W: Into<XYY<f64>>,
+    {
+        let c = c.into();
+        let white = white.into();
+        match self.dominant_wavelength(c, white) {
+            Some(d) => {
+                hypot(c.x - white.x, c.y - white.y)
+                    / hypot(d.boundary.x - white.x, d.boundary.y - white.y)
+            }
+            None => 0.0,
+        }
+    }
+
+    /// Compute the colorimetric purity of `c` relative to the white point
+    /// `white`, i.e. the excitation purity scaled by the ratio of the `y`
+    /// chromaticity coordinates of the boundary point and `c`.
+    ///
+    /// Returns 0 if `c` is the white point.
+    pub fn colorimetric_purity<C, W>(&self, c: C, white: W) -> f64
+    where
+        C: Into<XYY<f64>>,
+        W: Into<XYY<f64>>,
+    {
+        let c = c.into();
+        let white = white.into();
+        match self.dominant_wavelength(c, white) {
+            Some(d) => self.excitation_purity(c, white) * d.boundary.y / c.y,
+            None => 0.0,
+        }
+    }
+}
+
+/// Compute the convex hull of `points` with Andrew's monotone chain, in
+/// counter-clockwise order.
+fn convex_hull(points: &[XYY<f64>]) -> Vec<XYY<f64>> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap()
+            .then(a.y.partial_cmp(&b.y).unwrap())
+    });
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let cross = |o: &XYY<f64>, a: &XYY<f64>, b: &XYY<f64>| {
+        (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+    };
+    let reversed: Vec<XYY<f64>> = sorted.iter().rev().copied().collect();
+    let mut hull: Vec<XYY<f64>> = Vec::with_capacity(sorted.len() + 1);
+    // lower chain, then upper chain
+    for chain in &[sorted, reversed] {
+        let start = hull.len();
+        for p in chain {
+            while hull.len() >= start + 2
+                && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], p) <= 0.0
+            {
+                hull.pop();
+            }
+            hull.push(*p);
+        }
+        // the last point of each chain is the first of the next
+        hull.pop();
+    }
+    hull
+}
+
+#[test]
+fn test_spectral_locus() {
+    let locus = SpectralLocus::new(&crate::cmf::CIE_1931_2_DEGREE);
+    let d65 = crate::illuminant::xy::D65;
+    let i_520 = locus
+        .wavelengths()
+        .iter()
+        .position(|nm| *nm == 520.0)
+        .unwrap();
+    let i_580 = locus
+        .wavelengths()
+        .iter()
+        .position(|nm| *nm == 580.0)
+        .unwrap();
+
+    assert!(locus.contains(d65));
+    assert!(!locus.contains(xy(0.8, 0.8)));
+    assert!(!locus.contains(xy(0.05, 0.05)));
+
+    // just inside the 700-780 nm segment at the red end, where rounding in
+    // the tabulated functions makes the locus fold back on itself
+    for (nm, p) in locus.wavelengths().iter().zip(locus.points()) {
+        if *nm < 700.0 || *nm > 780.0 {
+            continue;
+        }
+        let toward =
+            |t: f64| xy(p.x + (d65.x - p.x) * t, p.y + (d65.y - p.y) * t);
+        assert!(locus.contains(toward(1e-7)));
+        assert!(!locus.contains(toward(-1e-3)));
+    }
+
+    // on the locus
+    let c = locus.points()[i_520];
+    let d = locus.dominant_wavelength(c, d65).unwrap();
+    assert!(!d.complementary);
+    assert!((d.wavelength - 520.0).abs() < 1e-6);
+    assert!((locus.excitation_purity(c, d65) - 1.0).abs() < 1e-9);
+    assert!((locus.colorimetric_purity(c, d65) - 1.0).abs() < 1e-9);
+
+    // half way between the white point and the locus
+    let p = locus.points()[i_580];
+    let c = xy((p.x + d65.x) * 0.5, (p.y + d65.y) * 0.5);
+    let d = locus.dominant_wavelength(c, d65).unwrap();
+    assert!((d.wavelength - 580.0).abs() < 1e-6);
+    assert!((locus.excitation_purity(c, d65) - 0.5).abs() < 1e-9);
+    assert!((locus.colorimetric_purity(c, d65) - 0.5 * p.y / c.y).abs() < 1e-9);
+
+    // purples have a complementary wavelength
+    let first = locus.points()[0];
+    let last = *locus.points().last().unwrap();
+    let purple = xy((first.x + last.x) * 0.5, (first.y + last.y) * 0.5);
+    let c = xy((purple.x + d65.x) * 0.5, (purple.y + d65.y) * 0.5);
+    let d = locus.dominant_wavelength(c, d65).unwrap();
+    assert!(d.complementary);
+    assert!(d.wavelength > 490.0 && d.wavelength < 570.0);
+    assert!((d.boundary.x - purple.x).abs() < 1e-9);
+    assert!((locus.excitation_purity(c, d65) - 0.5).abs() < 1e-9);
+
+    assert!(locus.dominant_wavelength(d65, d65).is_none());
+    assert_eq!(locus.excitation_purity(d65, d65), 0.0);
+}
@@ -1,6 +1,10 @@
 //! Tabulated data for Color Matching Functions.
 //!
-//! Currently only has the CIE 1931 2-degree standard observer
+//! Contains the CIE 1931 2-degree and CIE 1964 10-degree standard observers.
+//!
+//! The 10-degree observer is the 5nm table from CIE 15:2004; the official 1nm
+//! table is not included yet, nor are the CIE 2006 2-degree and 10-degree
+//! (physiologically based) observers.
 
 use lazy_static::lazy_static;
 
@@ -33,6 +37,7 @@ impl CMF {
 }
 
 lazy_static! {
+    /// CIE 1931 2-degree standard observer, tabulated at 1nm
     pub static ref CIE_1931_2_DEGREE: CMF = CMF {
         x_bar: vspd!(
         360.0 => 0.000129900000,
@@ -1454,4 +1459,338 @@ lazy_static! {
         830.0 => 0.000000000000
         ),
     };
+
+    /// CIE 1964 10-degree standard observer, tabulated at 5nm as published
+    /// in CIE 15:2004
+    pub static ref CIE_1964_10_DEGREE: CMF = CMF {
+        x_bar: vspd!(
+        360.0 => 0.000000122200,
+        365.0 => 0.000000919270,
+        370.0 => 0.000005958600,
+        375.0 => 0.000033266000,
+        380.0 => 0.000159952000,
+        385.0 => 0.000662440000,
+        390.0 => 0.002361600000,
+        395.0 => 0.007242300000,
+        400.0 => 0.019109700000,
+        405.0 => 0.043400000000,
+        410.0 => 0.084736000000,
+        415.0 => 0.140638000000,
+        420.0 => 0.204492000000,
+        425.0 => 0.264737000000,
+        430.0 => 0.314679000000,
+        435.0 => 0.357719000000,
+        440.0 => 0.383734000000,
+        445.0 => 0.386726000000,
+        450.0 => 0.370702000000,
+        455.0 => 0.342957000000,
+        460.0 => 0.302273000000,
+        465.0 => 0.254085000000,
+        470.0 => 0.195618000000,
+        475.0 => 0.132349000000,
+        480.0 => 0.080507000000,
+        485.0 => 0.041072000000,
+        490.0 => 0.016172000000,
+        495.0 => 0.005132000000,
+        500.0 => 0.003816000000,
+        505.0 => 0.015444000000,
+        510.0 => 0.037465000000,
+        515.0 => 0.071358000000,
+        520.0 => 0.117749000000,
+        525.0 => 0.172953000000,
+        530.0 => 0.236491000000,
+        535.0 => 0.304213000000,
+        540.0 => 0.376772000000,
+        545.0 => 0.451584000000,
+        550.0 => 0.529826000000,
+        555.0 => 0.616053000000,
+        560.0 => 0.705224000000,
+        565.0 => 0.793832000000,
+        570.0 => 0.878655000000,
+        575.0 => 0.951162000000,
+        580.0 => 1.014160000000,
+        585.0 => 1.074300000000,
+        590.0 => 1.118520000000,
+        595.0 => 1.134300000000,
+        600.0 => 1.123990000000,
+        605.0 => 1.089100000000,
+        610.0 => 1.030480000000,
+        615.0 => 0.950740000000,
+        620.0 => 0.856297000000,
+        625.0 => 0.754930000000,
+        630.0 => 0.647467000000,
+        635.0 => 0.535110000000,
+        640.0 => 0.431567000000,
+        645.0 => 0.343690000000,
+        650.0 => 0.268329000000,
+        655.0 => 0.204300000000,
+        660.0 => 0.152568000000,
+        665.0 => 0.112210000000,
+        670.0 => 0.081260600000,
+        675.0 => 0.057930000000,
+        680.0 => 0.040850800000,
+        685.0 => 0.028623000000,
+        690.0 => 0.019941300000,
+        695.0 => 0.013842000000,
+        700.0 => 0.009576880000,
+        705.0 => 0.006605200000,
+        710.0 => 0.004552630000,
+        715.0 => 0.003144700000,
+        720.0 => 0.002174960000,
+        725.0 => 0.001505700000,
+        730.0 => 0.001044760000,
+        735.0 => 0.000727450000,
+        740.0 => 0.000508258000,
+        745.0 => 0.000356380000,
+        750.0 => 0.000250969000,
+        755.0 => 0.000177730000,
+        760.0 => 0.000126390000,
+        765.0 => 0.000090151000,
+        770.0 => 0.000064525800,
+        775.0 => 0.000046339000,
+        780.0 => 0.000033411700,
+        785.0 => 0.000024209000,
+        790.0 => 0.000017611500,
+        795.0 => 0.000012855000,
+        800.0 => 0.000009413630,
+        805.0 => 0.000006913000,
+        810.0 => 0.000005093470,
+        815.0 => 0.000003767100,
+        820.0 => 0.000002795310,
+        825.0 => 0.000002082000,
+        830.0 => 0.000001553140
+        ),
+        y_bar: vspd!(
+        360.0 => 0.000000013398,
+        365.0 => 0.000000100650,
+        370.0 => 0.000000651100,
+        375.0 => 0.000003625000,
+        380.0 => 0.000017364000,
+        385.0 => 0.000071560000,
+        390.0 => 0.000253400000,
+        395.0 => 0.000768500000,
+        400.0 => 0.002004400000,
+        405.0 => 0.004509000000,
+        410.0 => 0.008756000000,
+        415.0 => 0.014456000000,
+        420.0 => 0.021391000000,
+        425.0 => 0.029497000000,
+        430.0 => 0.038676000000,
+        435.0 => 0.049602000000,
+        440.0 => 0.062077000000,
+        445.0 => 0.074704000000,
+        450.0 => 0.089456000000,
+        455.0 => 0.106256000000,
+        460.0 => 0.128201000000,
+        465.0 => 0.152761000000,
+        470.0 => 0.185190000000,
+        475.0 => 0.219940000000,
+        480.0 => 0.253589000000,
+        485.0 => 0.297665000000,
+        490.0 => 0.339133000000,
+        495.0 => 0.395379000000,
+        500.0 => 0.460777000000,
+        505.0 => 0.531360000000,
+        510.0 => 0.606741000000,
+        515.0 => 0.685660000000,
+        520.0 => 0.761757000000,
+        525.0 => 0.823330000000,
+        530.0 => 0.875211000000,
+        535.0 => 0.923810000000,
+        540.0 => 0.961988000000,
+        545.0 => 0.982200000000,
+        550.0 => 0.991761000000,
+        555.0 => 0.999110000000,
+        560.0 => 0.997340000000,
+        565.0 => 0.982380000000,
+        570.0 => 0.955552000000,
+        575.0 => 0.915175000000,
+        580.0 => 0.868934000000,
+        585.0 => 0.825623000000,
+        590.0 => 0.777405000000,
+        595.0 => 0.720353000000,
+        600.0 => 0.658341000000,
+        605.0 => 0.593878000000,
+        610.0 => 0.527963000000,
+        615.0 => 0.461834000000,
+        620.0 => 0.398057000000,
+        625.0 => 0.339554000000,
+        630.0 => 0.283493000000,
+        635.0 => 0.228254000000,
+        640.0 => 0.179828000000,
+        645.0 => 0.140211000000,
+        650.0 => 0.107633000000,
+        655.0 => 0.081187000000,
+        660.0 => 0.060281000000,
+        665.0 => 0.044096000000,
+        670.0 => 0.031800400000,
+        675.0 => 0.022601700000,
+        680.0 => 0.015905100000,
+        685.0 => 0.011130300000,
+        690.0 => 0.007748800000,
+        695.0 => 0.005375100000,
+        700.0 => 0.003717740000,
+        705.0 => 0.002564560000,
+        710.0 => 0.001768470000,
+        715.0 => 0.001222390000,
+        720.0 => 0.000846190000,
+        725.0 => 0.000586440000,
+        730.0 => 0.000407410000,
+        735.0 => 0.000284041000,
+        740.0 => 0.000198730000,
+        745.0 => 0.000139550000,
+        750.0 => 0.000098428000,
+        755.0 => 0.000069819000,
+        760.0 => 0.000049737000,
+        765.0 => 0.000035540500,
+        770.0 => 0.000025486000,
+        775.0 => 0.000018338400,
+        780.0 => 0.000013249000,
+        785.0 => 0.000009619600,
+        790.0 => 0.000007012800,
+        795.0 => 0.000005129800,
+        800.0 => 0.000003764730,
+        805.0 => 0.000002770810,
+        810.0 => 0.000002046130,
+        815.0 => 0.000001516770,
+        820.0 => 0.000001128090,
+        825.0 => 0.000000842160,
+        830.0 => 0.000000629700
+        ),
+        z_bar: vspd!(
+        360.0 => 0.000000535027,
+        365.0 => 0.000004028300,
+        370.0 => 0.000026143700,
+        375.0 => 0.000146220000,
+        380.0 => 0.000704776000,
+        385.0 => 0.002927800000,
+        390.0 => 0.010482200000,
+        395.0 => 0.032344000000,
+        400.0 => 0.086010900000,
+        405.0 => 0.197120000000,
+        410.0 => 0.389366000000,
+        415.0 => 0.656760000000,
+        420.0 => 0.972542000000,
+        425.0 => 1.282500000000,
+        430.0 => 1.553480000000,
+        435.0 => 1.798500000000,
+        440.0 => 1.967280000000,
+        445.0 => 2.027300000000,
+        450.0 => 1.994800000000,
+        455.0 => 1.900700000000,
+        460.0 => 1.745370000000,
+        465.0 => 1.554900000000,
+        470.0 => 1.317560000000,
+        475.0 => 1.030200000000,
+        480.0 => 0.772125000000,
+        485.0 => 0.570060000000,
+        490.0 => 0.415254000000,
+        495.0 => 0.302356000000,
+        500.0 => 0.218502000000,
+        505.0 => 0.159249000000,
+        510.0 => 0.112044000000,
+        515.0 => 0.082248000000,
+        520.0 => 0.060709000000,
+        525.0 => 0.043050000000,
+        530.0 => 0.030451000000,
+        535.0 => 0.020584000000,
+        540.0 => 0.013676000000,
+        545.0 => 0.007918000000,
+        550.0 => 0.003988000000,
+        555.0 => 0.001091000000,
+        560.0 => 0.000000000000,
+        565.0 => 0.000000000000,
+        570.0 => 0.000000000000,
+        575.0 => 0.000000000000,
+        580.0 => 0.000000000000,
+        585.0 => 0.000000000000,
+        590.0 => 0.000000000000,
+        595.0 => 0.000000000000,
+        600.0 => 0.000000000000,
+        605.0 => 0.000000000000,
+        610.0 => 0.000000000000,
+        615.0 => 0.000000000000,
+        620.0 => 0.000000000000,
+        625.0 => 0.000000000000,
+        630.0 => 0.000000000000,
+        635.0 => 0.000000000000,
+        640.0 => 0.000000000000,
+        645.0 => 0.000000000000,
+        650.0 => 0.000000000000,
+        655.0 => 0.000000000000,
+        660.0 => 0.000000000000,
+        665.0 => 0.000000000000,
+        670.0 => 0.000000000000,
+        675.0 => 0.000000000000,
+        680.0 => 0.000000000000,
+        685.0 => 0.000000000000,
+        690.0 => 0.000000000000,
+        695.0 => 0.000000000000,
+        700.0 => 0.000000000000,
+        705.0 => 0.000000000000,
+        710.0 => 0.000000000000,
+        715.0 => 0.000000000000,
+        720.0 => 0.000000000000,
+        725.0 => 0.000000000000,
+        730.0 => 0.000000000000,
+        735.0 => 0.000000000000,
+        740.0 => 0.000000000000,
+        745.0 => 0.000000000000,
+        750.0 => 0.000000000000,
+        755.0 => 0.000000000000,
+        760.0 => 0.000000000000,
+        765.0 => 0.000000000000,
+        770.0 => 0.000000000000,
+        775.0 => 0.000000000000,
+        780.0 => 0.000000000000,
+        785.0 => 0.000000000000,
+        790.0 => 0.000000000000,
+        795.0 => 0.000000000000,
+        800.0 => 0.000000000000,
+        805.0 => 0.000000000000,
+        810.0 => 0.000000000000,
+        815.0 => 0.000000000000,
+        820.0 => 0.000000000000,
+        825.0 => 0.000000000000,
+        830.0 => 0.000000000000
+        ),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::illuminant;
+
+    fn round_to_places(x: f64, p: i32) -> f64 {
+        (x * 10f64.powi(p)).round() / 10f64.powi(p)
+    }
+
+    #[test]
+    fn white_points_10_degree() {
+        // chromaticities of the perfect diffuser under the 10-degree observer
+        // as published in CIE 15:2004, table T.3
+        let white = VSPD::constant(SpdShape::new(360.0, 830.0, 5.0), 1.0);
+        for (ill, x_ref, y_ref) in vec![
+            (&*illuminant::spd::D50, 0.3477, 0.3595),
+            (&*illuminant::spd::D55, 0.3341, 0.3488),
+            (&*illuminant::spd::D65, 0.3138, 0.3310),
+        ] {
+            let xyz = white.to_xyz(ill, &CIE_1964_10_DEGREE);
+            let c = XYYf64::from(xyz);
+            assert_eq!(round_to_places(c.x, 4), x_ref);
+            assert_eq!(round_to_places(c.y, 4), y_ref);
+        }
+    }
+
+    #[test]
+    fn equal_energy_10_degree() {
+        // the CMFs are normalized so that the equal-energy illuminant has
+        // equal tristimulus values
+        let x = CIE_1964_10_DEGREE.x_bar.values().sum::<f64>();
+        let y = CIE_1964_10_DEGREE.y_bar.values().sum::<f64>();
+        let z = CIE_1964_10_DEGREE.z_bar.values().sum::<f64>();
+        assert!((x / y - 1.0).abs() < 1e-3);
+        assert!((z / y - 1.0).abs() < 1e-3);
+    }
 }
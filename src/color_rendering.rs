@@ -0,0 +1,1128 @@
+//! Colour rendering metrics for light sources: the CIE 13.3 colour rendering
+//! index and the IES TM-30-20 fidelity and gamut indices.
+//!
+//! Both metrics compare the colours of a set of reflectance samples lit by a
+//! test source with the colours of the same samples lit by a reference
+//! illuminant of the same CCT. [cri] uses the CIE test colour samples
+//! TCS01–TCS08 bundled as [static@TCS], which give Ra and R1–R8. The
+//! remaining CIE samples TCS09–TCS14 and the 99 IES colour evaluation
+//! samples used by TM-30-20 are not bundled, so for R9–R14 and for TM-30 the
+//! sample reflectances are passed in as a slice of [VSPD]s to
+//! [cri_with_samples] and [tm30_with_samples]. The indices are only
+//! comparable with published values when the standard sets are used.
+//!
+//! See CIE 13.3-1995, "Method of Measuring and Specifying Colour Rendering
+//! Properties of Light Sources", and ANSI/IES TM-30-20, "IES Method for
+//! Evaluating Light Source Color Rendition".
+#![allow(clippy::approx_constant)]
+use crate::appearance::{
+    jmh_to_jab, xyz_to_ciecam02, Jab, Surround, UniformSpace, ViewingConditions,
+};
+use crate::cct::xy_to_cct_ohno2013;
+use crate::chromaticity::XYY;
+use crate::cmf::{CIE_1931_2_DEGREE, CIE_1964_10_DEGREE, CMF};
+use crate::illuminant::spd::{blackbody, daylight};
+use crate::math::atan2;
+use crate::vspd::{Integration, SpdShape, VSPD};
+use crate::xyz::XYZf64;
+
+use lazy_static::lazy_static;
+
+use std::f64::consts::PI;
+
+lazy_static! {
+    /// The first eight CIE 13.3 test colour samples, TCS01–TCS08, from which
+    /// the general colour rendering index Ra is computed, tabulated from
+    /// 380nm to 780nm at 5nm. `TCS[0]` is TCS01.
+    pub static ref TCS: Vec<VSPD> = vec![
+        // TCS01: light greyish red, 7.5R 6/4
+        vspd! {
+            380.0 => 0.219,
+            385.0 => 0.239,
+            390.0 => 0.252,
+            395.0 => 0.256,
+            400.0 => 0.256,
+            405.0 => 0.254,
+            410.0 => 0.252,
+            415.0 => 0.248,
+            420.0 => 0.244,
+            425.0 => 0.240,
+            430.0 => 0.237,
+            435.0 => 0.232,
+            440.0 => 0.230,
+            445.0 => 0.226,
+            450.0 => 0.225,
+            455.0 => 0.222,
+            460.0 => 0.220,
+            465.0 => 0.218,
+            470.0 => 0.216,
+            475.0 => 0.214,
+            480.0 => 0.214,
+            485.0 => 0.214,
+            490.0 => 0.216,
+            495.0 => 0.218,
+            500.0 => 0.223,
+            505.0 => 0.225,
+            510.0 => 0.226,
+            515.0 => 0.226,
+            520.0 => 0.225,
+            525.0 => 0.225,
+            530.0 => 0.227,
+            535.0 => 0.230,
+            540.0 => 0.236,
+            545.0 => 0.245,
+            550.0 => 0.253,
+            555.0 => 0.262,
+            560.0 => 0.272,
+            565.0 => 0.283,
+            570.0 => 0.298,
+            575.0 => 0.318,
+            580.0 => 0.341,
+            585.0 => 0.367,
+            590.0 => 0.390,
+            595.0 => 0.409,
+            600.0 => 0.424,
+            605.0 => 0.435,
+            610.0 => 0.442,
+            615.0 => 0.448,
+            620.0 => 0.450,
+            625.0 => 0.451,
+            630.0 => 0.451,
+            635.0 => 0.451,
+            640.0 => 0.451,
+            645.0 => 0.451,
+            650.0 => 0.450,
+            655.0 => 0.450,
+            660.0 => 0.451,
+            665.0 => 0.451,
+            670.0 => 0.453,
+            675.0 => 0.455,
+            680.0 => 0.459,
+            685.0 => 0.462,
+            690.0 => 0.464,
+            695.0 => 0.466,
+            700.0 => 0.466,
+            705.0 => 0.466,
+            710.0 => 0.466,
+            715.0 => 0.467,
+            720.0 => 0.467,
+            725.0 => 0.467,
+            730.0 => 0.467,
+            735.0 => 0.467,
+            740.0 => 0.467,
+            745.0 => 0.467,
+            750.0 => 0.467,
+            755.0 => 0.467,
+            760.0 => 0.467,
+            765.0 => 0.467,
+            770.0 => 0.467,
+            775.0 => 0.467,
+            780.0 => 0.467,
+        },
+        // TCS02: dark greyish yellow, 5Y 6/4
+        vspd! {
+            380.0 => 0.070,
+            385.0 => 0.079,
+            390.0 => 0.089,
+            395.0 => 0.101,
+            400.0 => 0.111,
+            405.0 => 0.116,
+            410.0 => 0.118,
+            415.0 => 0.120,
+            420.0 => 0.121,
+            425.0 => 0.122,
+            430.0 => 0.122,
+            435.0 => 0.122,
+            440.0 => 0.123,
+            445.0 => 0.124,
+            450.0 => 0.127,
+            455.0 => 0.128,
+            460.0 => 0.131,
+            465.0 => 0.134,
+            470.0 => 0.138,
+            475.0 => 0.143,
+            480.0 => 0.150,
+            485.0 => 0.159,
+            490.0 => 0.174,
+            495.0 => 0.190,
+            500.0 => 0.207,
+            505.0 => 0.225,
+            510.0 => 0.242,
+            515.0 => 0.253,
+            520.0 => 0.260,
+            525.0 => 0.264,
+            530.0 => 0.267,
+            535.0 => 0.269,
+            540.0 => 0.272,
+            545.0 => 0.276,
+            550.0 => 0.282,
+            555.0 => 0.289,
+            560.0 => 0.299,
+            565.0 => 0.309,
+            570.0 => 0.322,
+            575.0 => 0.329,
+            580.0 => 0.335,
+            585.0 => 0.339,
+            590.0 => 0.341,
+            595.0 => 0.341,
+            600.0 => 0.342,
+            605.0 => 0.342,
+            610.0 => 0.342,
+            615.0 => 0.341,
+            620.0 => 0.341,
+            625.0 => 0.339,
+            630.0 => 0.339,
+            635.0 => 0.338,
+            640.0 => 0.338,
+            645.0 => 0.337,
+            650.0 => 0.336,
+            655.0 => 0.335,
+            660.0 => 0.334,
+            665.0 => 0.332,
+            670.0 => 0.332,
+            675.0 => 0.331,
+            680.0 => 0.331,
+            685.0 => 0.330,
+            690.0 => 0.329,
+            695.0 => 0.328,
+            700.0 => 0.328,
+            705.0 => 0.327,
+            710.0 => 0.326,
+            715.0 => 0.325,
+            720.0 => 0.324,
+            725.0 => 0.324,
+            730.0 => 0.324,
+            735.0 => 0.323,
+            740.0 => 0.322,
+            745.0 => 0.321,
+            750.0 => 0.320,
+            755.0 => 0.318,
+            760.0 => 0.316,
+            765.0 => 0.315,
+            770.0 => 0.315,
+            775.0 => 0.314,
+            780.0 => 0.314,
+        },
+        // TCS03: strong yellow green, 5GY 6/8
+        vspd! {
+            380.0 => 0.065,
+            385.0 => 0.068,
+            390.0 => 0.070,
+            395.0 => 0.072,
+            400.0 => 0.073,
+            405.0 => 0.073,
+            410.0 => 0.074,
+            415.0 => 0.074,
+            420.0 => 0.074,
+            425.0 => 0.073,
+            430.0 => 0.073,
+            435.0 => 0.073,
+            440.0 => 0.073,
+            445.0 => 0.073,
+            450.0 => 0.074,
+            455.0 => 0.075,
+            460.0 => 0.077,
+            465.0 => 0.080,
+            470.0 => 0.085,
+            475.0 => 0.094,
+            480.0 => 0.109,
+            485.0 => 0.126,
+            490.0 => 0.148,
+            495.0 => 0.172,
+            500.0 => 0.198,
+            505.0 => 0.221,
+            510.0 => 0.241,
+            515.0 => 0.260,
+            520.0 => 0.278,
+            525.0 => 0.302,
+            530.0 => 0.339,
+            535.0 => 0.370,
+            540.0 => 0.392,
+            545.0 => 0.399,
+            550.0 => 0.400,
+            555.0 => 0.393,
+            560.0 => 0.380,
+            565.0 => 0.365,
+            570.0 => 0.349,
+            575.0 => 0.332,
+            580.0 => 0.315,
+            585.0 => 0.299,
+            590.0 => 0.285,
+            595.0 => 0.272,
+            600.0 => 0.264,
+            605.0 => 0.257,
+            610.0 => 0.252,
+            615.0 => 0.247,
+            620.0 => 0.241,
+            625.0 => 0.235,
+            630.0 => 0.229,
+            635.0 => 0.224,
+            640.0 => 0.220,
+            645.0 => 0.217,
+            650.0 => 0.216,
+            655.0 => 0.216,
+            660.0 => 0.219,
+            665.0 => 0.224,
+            670.0 => 0.230,
+            675.0 => 0.238,
+            680.0 => 0.251,
+            685.0 => 0.269,
+            690.0 => 0.288,
+            695.0 => 0.312,
+            700.0 => 0.340,
+            705.0 => 0.366,
+            710.0 => 0.390,
+            715.0 => 0.412,
+            720.0 => 0.431,
+            725.0 => 0.447,
+            730.0 => 0.460,
+            735.0 => 0.472,
+            740.0 => 0.481,
+            745.0 => 0.488,
+            750.0 => 0.493,
+            755.0 => 0.496,
+            760.0 => 0.499,
+            765.0 => 0.500,
+            770.0 => 0.501,
+            775.0 => 0.502,
+            780.0 => 0.502,
+        },
+        // TCS04: moderate yellowish green, 2.5G 6/6
+        vspd! {
+            380.0 => 0.074,
+            385.0 => 0.083,
+            390.0 => 0.093,
+            395.0 => 0.105,
+            400.0 => 0.116,
+            405.0 => 0.121,
+            410.0 => 0.124,
+            415.0 => 0.126,
+            420.0 => 0.128,
+            425.0 => 0.131,
+            430.0 => 0.135,
+            435.0 => 0.139,
+            440.0 => 0.144,
+            445.0 => 0.151,
+            450.0 => 0.161,
+            455.0 => 0.172,
+            460.0 => 0.186,
+            465.0 => 0.205,
+            470.0 => 0.229,
+            475.0 => 0.254,
+            480.0 => 0.281,
+            485.0 => 0.308,
+            490.0 => 0.332,
+            495.0 => 0.352,
+            500.0 => 0.370,
+            505.0 => 0.383,
+            510.0 => 0.390,
+            515.0 => 0.394,
+            520.0 => 0.395,
+            525.0 => 0.392,
+            530.0 => 0.385,
+            535.0 => 0.377,
+            540.0 => 0.367,
+            545.0 => 0.354,
+            550.0 => 0.341,
+            555.0 => 0.327,
+            560.0 => 0.312,
+            565.0 => 0.296,
+            570.0 => 0.280,
+            575.0 => 0.263,
+            580.0 => 0.247,
+            585.0 => 0.229,
+            590.0 => 0.214,
+            595.0 => 0.198,
+            600.0 => 0.185,
+            605.0 => 0.175,
+            610.0 => 0.169,
+            615.0 => 0.164,
+            620.0 => 0.160,
+            625.0 => 0.156,
+            630.0 => 0.154,
+            635.0 => 0.152,
+            640.0 => 0.151,
+            645.0 => 0.149,
+            650.0 => 0.148,
+            655.0 => 0.148,
+            660.0 => 0.148,
+            665.0 => 0.149,
+            670.0 => 0.151,
+            675.0 => 0.154,
+            680.0 => 0.158,
+            685.0 => 0.162,
+            690.0 => 0.165,
+            695.0 => 0.168,
+            700.0 => 0.170,
+            705.0 => 0.171,
+            710.0 => 0.170,
+            715.0 => 0.168,
+            720.0 => 0.166,
+            725.0 => 0.164,
+            730.0 => 0.164,
+            735.0 => 0.165,
+            740.0 => 0.168,
+            745.0 => 0.172,
+            750.0 => 0.177,
+            755.0 => 0.181,
+            760.0 => 0.185,
+            765.0 => 0.189,
+            770.0 => 0.192,
+            775.0 => 0.194,
+            780.0 => 0.197,
+        },
+        // TCS05: light bluish green, 10BG 6/4
+        vspd! {
+            380.0 => 0.295,
+            385.0 => 0.306,
+            390.0 => 0.310,
+            395.0 => 0.312,
+            400.0 => 0.313,
+            405.0 => 0.315,
+            410.0 => 0.319,
+            415.0 => 0.322,
+            420.0 => 0.326,
+            425.0 => 0.330,
+            430.0 => 0.334,
+            435.0 => 0.339,
+            440.0 => 0.346,
+            445.0 => 0.352,
+            450.0 => 0.360,
+            455.0 => 0.369,
+            460.0 => 0.381,
+            465.0 => 0.394,
+            470.0 => 0.403,
+            475.0 => 0.410,
+            480.0 => 0.415,
+            485.0 => 0.418,
+            490.0 => 0.419,
+            495.0 => 0.417,
+            500.0 => 0.413,
+            505.0 => 0.409,
+            510.0 => 0.403,
+            515.0 => 0.396,
+            520.0 => 0.389,
+            525.0 => 0.381,
+            530.0 => 0.372,
+            535.0 => 0.363,
+            540.0 => 0.353,
+            545.0 => 0.342,
+            550.0 => 0.331,
+            555.0 => 0.320,
+            560.0 => 0.308,
+            565.0 => 0.296,
+            570.0 => 0.284,
+            575.0 => 0.271,
+            580.0 => 0.260,
+            585.0 => 0.247,
+            590.0 => 0.232,
+            595.0 => 0.220,
+            600.0 => 0.210,
+            605.0 => 0.200,
+            610.0 => 0.194,
+            615.0 => 0.189,
+            620.0 => 0.185,
+            625.0 => 0.183,
+            630.0 => 0.180,
+            635.0 => 0.177,
+            640.0 => 0.176,
+            645.0 => 0.175,
+            650.0 => 0.175,
+            655.0 => 0.175,
+            660.0 => 0.175,
+            665.0 => 0.177,
+            670.0 => 0.180,
+            675.0 => 0.183,
+            680.0 => 0.186,
+            685.0 => 0.189,
+            690.0 => 0.192,
+            695.0 => 0.195,
+            700.0 => 0.199,
+            705.0 => 0.200,
+            710.0 => 0.199,
+            715.0 => 0.198,
+            720.0 => 0.196,
+            725.0 => 0.195,
+            730.0 => 0.195,
+            735.0 => 0.196,
+            740.0 => 0.197,
+            745.0 => 0.200,
+            750.0 => 0.203,
+            755.0 => 0.205,
+            760.0 => 0.208,
+            765.0 => 0.212,
+            770.0 => 0.215,
+            775.0 => 0.217,
+            780.0 => 0.219,
+        },
+        // TCS06: light blue, 5PB 6/8
+        vspd! {
+            380.0 => 0.151,
+            385.0 => 0.203,
+            390.0 => 0.265,
+            395.0 => 0.339,
+            400.0 => 0.410,
+            405.0 => 0.464,
+            410.0 => 0.492,
+            415.0 => 0.508,
+            420.0 => 0.517,
+            425.0 => 0.524,
+            430.0 => 0.531,
+            435.0 => 0.538,
+            440.0 => 0.544,
+            445.0 => 0.551,
+            450.0 => 0.556,
+            455.0 => 0.556,
+            460.0 => 0.554,
+            465.0 => 0.549,
+            470.0 => 0.541,
+            475.0 => 0.531,
+            480.0 => 0.519,
+            485.0 => 0.504,
+            490.0 => 0.488,
+            495.0 => 0.469,
+            500.0 => 0.450,
+            505.0 => 0.431,
+            510.0 => 0.414,
+            515.0 => 0.395,
+            520.0 => 0.377,
+            525.0 => 0.358,
+            530.0 => 0.341,
+            535.0 => 0.325,
+            540.0 => 0.309,
+            545.0 => 0.293,
+            550.0 => 0.279,
+            555.0 => 0.265,
+            560.0 => 0.253,
+            565.0 => 0.241,
+            570.0 => 0.234,
+            575.0 => 0.227,
+            580.0 => 0.225,
+            585.0 => 0.222,
+            590.0 => 0.221,
+            595.0 => 0.220,
+            600.0 => 0.220,
+            605.0 => 0.220,
+            610.0 => 0.220,
+            615.0 => 0.220,
+            620.0 => 0.223,
+            625.0 => 0.227,
+            630.0 => 0.233,
+            635.0 => 0.239,
+            640.0 => 0.244,
+            645.0 => 0.251,
+            650.0 => 0.258,
+            655.0 => 0.263,
+            660.0 => 0.268,
+            665.0 => 0.273,
+            670.0 => 0.278,
+            675.0 => 0.281,
+            680.0 => 0.283,
+            685.0 => 0.286,
+            690.0 => 0.291,
+            695.0 => 0.296,
+            700.0 => 0.302,
+            705.0 => 0.313,
+            710.0 => 0.325,
+            715.0 => 0.338,
+            720.0 => 0.351,
+            725.0 => 0.364,
+            730.0 => 0.376,
+            735.0 => 0.389,
+            740.0 => 0.401,
+            745.0 => 0.413,
+            750.0 => 0.425,
+            755.0 => 0.436,
+            760.0 => 0.447,
+            765.0 => 0.458,
+            770.0 => 0.469,
+            775.0 => 0.477,
+            780.0 => 0.485,
+        },
+        // TCS07: light violet, 2.5P 6/8
+        vspd! {
+            380.0 => 0.378,
+            385.0 => 0.459,
+            390.0 => 0.524,
+            395.0 => 0.546,
+            400.0 => 0.551,
+            405.0 => 0.555,
+            410.0 => 0.559,
+            415.0 => 0.560,
+            420.0 => 0.561,
+            425.0 => 0.558,
+            430.0 => 0.556,
+            435.0 => 0.551,
+            440.0 => 0.544,
+            445.0 => 0.535,
+            450.0 => 0.522,
+            455.0 => 0.506,
+            460.0 => 0.488,
+            465.0 => 0.469,
+            470.0 => 0.448,
+            475.0 => 0.429,
+            480.0 => 0.408,
+            485.0 => 0.385,
+            490.0 => 0.363,
+            495.0 => 0.341,
+            500.0 => 0.324,
+            505.0 => 0.311,
+            510.0 => 0.301,
+            515.0 => 0.291,
+            520.0 => 0.283,
+            525.0 => 0.273,
+            530.0 => 0.265,
+            535.0 => 0.260,
+            540.0 => 0.257,
+            545.0 => 0.257,
+            550.0 => 0.259,
+            555.0 => 0.260,
+            560.0 => 0.260,
+            565.0 => 0.258,
+            570.0 => 0.256,
+            575.0 => 0.254,
+            580.0 => 0.254,
+            585.0 => 0.259,
+            590.0 => 0.270,
+            595.0 => 0.284,
+            600.0 => 0.302,
+            605.0 => 0.324,
+            610.0 => 0.344,
+            615.0 => 0.362,
+            620.0 => 0.377,
+            625.0 => 0.389,
+            630.0 => 0.400,
+            635.0 => 0.410,
+            640.0 => 0.420,
+            645.0 => 0.429,
+            650.0 => 0.438,
+            655.0 => 0.445,
+            660.0 => 0.452,
+            665.0 => 0.457,
+            670.0 => 0.462,
+            675.0 => 0.466,
+            680.0 => 0.468,
+            685.0 => 0.470,
+            690.0 => 0.473,
+            695.0 => 0.477,
+            700.0 => 0.483,
+            705.0 => 0.489,
+            710.0 => 0.496,
+            715.0 => 0.503,
+            720.0 => 0.511,
+            725.0 => 0.518,
+            730.0 => 0.525,
+            735.0 => 0.532,
+            740.0 => 0.539,
+            745.0 => 0.546,
+            750.0 => 0.553,
+            755.0 => 0.559,
+            760.0 => 0.565,
+            765.0 => 0.570,
+            770.0 => 0.575,
+            775.0 => 0.578,
+            780.0 => 0.581,
+        },
+        // TCS08: light reddish purple, 10P 6/8
+        vspd! {
+            380.0 => 0.104,
+            385.0 => 0.129,
+            390.0 => 0.170,
+            395.0 => 0.240,
+            400.0 => 0.319,
+            405.0 => 0.416,
+            410.0 => 0.462,
+            415.0 => 0.482,
+            420.0 => 0.490,
+            425.0 => 0.488,
+            430.0 => 0.482,
+            435.0 => 0.473,
+            440.0 => 0.462,
+            445.0 => 0.450,
+            450.0 => 0.439,
+            455.0 => 0.426,
+            460.0 => 0.413,
+            465.0 => 0.397,
+            470.0 => 0.382,
+            475.0 => 0.366,
+            480.0 => 0.352,
+            485.0 => 0.337,
+            490.0 => 0.325,
+            495.0 => 0.310,
+            500.0 => 0.299,
+            505.0 => 0.289,
+            510.0 => 0.283,
+            515.0 => 0.276,
+            520.0 => 0.270,
+            525.0 => 0.262,
+            530.0 => 0.256,
+            535.0 => 0.251,
+            540.0 => 0.250,
+            545.0 => 0.251,
+            550.0 => 0.254,
+            555.0 => 0.258,
+            560.0 => 0.264,
+            565.0 => 0.269,
+            570.0 => 0.272,
+            575.0 => 0.274,
+            580.0 => 0.278,
+            585.0 => 0.284,
+            590.0 => 0.295,
+            595.0 => 0.316,
+            600.0 => 0.348,
+            605.0 => 0.384,
+            610.0 => 0.434,
+            615.0 => 0.482,
+            620.0 => 0.528,
+            625.0 => 0.568,
+            630.0 => 0.604,
+            635.0 => 0.629,
+            640.0 => 0.648,
+            645.0 => 0.663,
+            650.0 => 0.676,
+            655.0 => 0.685,
+            660.0 => 0.693,
+            665.0 => 0.700,
+            670.0 => 0.705,
+            675.0 => 0.709,
+            680.0 => 0.712,
+            685.0 => 0.715,
+            690.0 => 0.717,
+            695.0 => 0.719,
+            700.0 => 0.721,
+            705.0 => 0.720,
+            710.0 => 0.719,
+            715.0 => 0.722,
+            720.0 => 0.725,
+            725.0 => 0.727,
+            730.0 => 0.729,
+            735.0 => 0.730,
+            740.0 => 0.730,
+            745.0 => 0.730,
+            750.0 => 0.730,
+            755.0 => 0.730,
+            760.0 => 0.730,
+            765.0 => 0.730,
+            770.0 => 0.730,
+            775.0 => 0.730,
+            780.0 => 0.730,
+        },
+    ];
+}
+
+/// Number of hue bins used by TM-30-20 to compute the gamut index
+pub const TM30_HUE_BINS: usize = 16;
+
+/// The CIE 13.3 colour rendering indices of a test source
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorRenderingIndex {
+    /// Correlated colour temperature of the test source, in Kelvin
+    pub cct: f64,
+    /// Distance of the test source from the Planckian locus. CIE 13.3
+    /// considers the index meaningful only when this is below 5.4e-3
+    pub duv: f64,
+    /// General colour rendering index Ra, the mean of the first eight
+    /// special indices
+    pub ra: f64,
+    /// Special colour rendering index Ri of each sample, in order, so that
+    /// with the CIE test colour samples `ri[0]` is R1
+    pub ri: Vec<f64>,
+}
+
+/// The IES TM-30-20 fidelity and gamut indices of a test source
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tm30 {
+    /// Correlated colour temperature of the test source, in Kelvin
+    pub cct: f64,
+    /// Distance of the test source from the Planckian locus
+    pub duv: f64,
+    /// Fidelity index Rf
+    pub rf: f64,
+    /// Gamut index Rg
+    pub rg: f64,
+    /// Fidelity index Rf,i of each sample, in order
+    pub rf_i: Vec<f64>,
+}
+
+/// Returns a flat reflectance of 1.0, whose tristimulus values are those of
+/// the illuminant it is lit by
+fn perfect_diffuser() -> VSPD {
+    SpdShape::new(360.0, 780.0, 5.0)
+        .iter()
+        .map(|nm| (nm, 1.0))
+        .collect()
+}
+
+/// Returns the CCT and Duv of the light source `spd`
+fn source_cct(spd: &VSPD) -> (f64, f64) {
+    let white = perfect_diffuser().to_xyz(spd, &CIE_1931_2_DEGREE);
+    xy_to_cct_ohno2013(XYY::from(white))
+}
+
+/// Returns the reference illuminant used by CIE 13.3 for a test source with
+/// the given `cct`: a Planckian radiator below 5000K and a CIE daylight
+/// illuminant at or above it.
+/// # Panics
+/// Panics if `cct` is above 25000K, the limit of the daylight model
+pub fn cri_reference_illuminant(cct: f64) -> VSPD {
+    if cct < 5000.0 {
+        blackbody(cct, SpdShape::new(300.0, 830.0, 5.0))
+    } else {
+        daylight(cct)
+    }
+}
+
+/// Returns the reference illuminant used by TM-30-20 for a test source with
+/// the given `cct`: a Planckian radiator up to 4000K, a CIE daylight
+/// illuminant from 5000K, and a blend of the two in between with each
+/// normalized to the same luminance.
+/// # Panics
+/// Panics if `cct` is above 25000K, the limit of the daylight model
+pub fn tm30_reference_illuminant(cct: f64) -> VSPD {
+    if cct <= 4000.0 {
+        return blackbody(cct, SpdShape::new(300.0, 830.0, 5.0));
+    }
+    if cct >= 5000.0 {
+        return daylight(cct);
+    }
+
+    let luminance = |s: &VSPD| {
+        (s * &CIE_1964_10_DEGREE.y_bar).integrate(Integration::Trapezoidal)
+    };
+    let p = blackbody(cct, SpdShape::new(300.0, 830.0, 5.0));
+    let d = daylight(cct);
+    let p = &p / luminance(&p);
+    let d = &d / luminance(&d);
+
+    let t = (cct - 4000.0) / 1000.0;
+    p * (1.0 - t) + d * t
+}
+
+/// Returns the XYZ of the perfect diffuser followed by the XYZ of each of
+/// `samples`, all lit by `illuminant` and normalized so the diffuser has a Y
+/// of 100
+fn sample_xyz(
+    illuminant: &VSPD,
+    samples: &[VSPD],
+    cmf: &CMF,
+) -> (XYZf64, Vec<XYZf64>) {
+    (
+        perfect_diffuser().to_xyz(illuminant, cmf),
+        samples.iter().map(|s| s.to_xyz(illuminant, cmf)).collect(),
+    )
+}
+
+/// Convert XYZ to CIE 1960 UCS uv
+fn xyz_to_uv(c: XYZf64) -> (f64, f64) {
+    let d = c.x + 15.0 * c.y + 3.0 * c.z;
+    (4.0 * c.x / d, 6.0 * c.y / d)
+}
+
+/// The c and d coefficients of the CIE 13.3 von Kries transform
+fn von_kries_cd(u: f64, v: f64) -> (f64, f64) {
+    (
+        (4.0 - u - 10.0 * v) / v,
+        (1.708 * v + 0.404 - 1.481 * u) / v,
+    )
+}
+
+/// Convert `xyz` with white point `(u_w, v_w)` to CIE 1964 U*V*W*, using the
+/// uv chromaticity `(u, v)` given separately so that adapted chromaticities
+/// may be used
+fn uvw(xyz: XYZf64, u: f64, v: f64, u_w: f64, v_w: f64) -> [f64; 3] {
+    let w = 25.0 * xyz.y.cbrt() - 17.0;
+    [13.0 * w * (u - u_w), 13.0 * w * (v - v_w), w]
+}
+
+/// Compute the CIE 13.3 general colour rendering index Ra and the special
+/// indices R1–R8 of the light source `test`, using the test colour samples
+/// in [static@TCS].
+///
+/// ```
+/// use colorspace::color_rendering::cri;
+/// use colorspace::illuminant::spd::F2;
+/// let c = cri(&F2);
+/// assert_eq!(c.ra.round(), 64.0);
+/// ```
+/// # Panics
+/// Panics if the CCT of `test` is above 25000K
+pub fn cri(test: &VSPD) -> ColorRenderingIndex {
+    cri_with_samples(test, &TCS)
+}
+
+/// Compute the CIE 13.3 colour rendering indices of the light source `test`
+/// for the reflectance `samples`.
+///
+/// Each sample is lit by `test` and by the reference illuminant of the same
+/// CCT given by [cri_reference_illuminant], the test colours are adapted to
+/// the reference white with the CIE 13.3 von Kries transform in the CIE 1960
+/// UCS, and the special index of each sample is `Ri = 100 - 4.6ΔE` where ΔE
+/// is the colour difference in U*V*W*. Ra is the mean of the first eight Ri,
+/// or of all of them if there are fewer than eight samples.
+///
+/// The CIE test colour samples TCS01–TCS14 give the standard indices
+/// R1–R14.
+/// # Panics
+/// Panics if `samples` is empty, or if the CCT of `test` is above 25000K
+pub fn cri_with_samples(test: &VSPD, samples: &[VSPD]) -> ColorRenderingIndex {
+    if samples.is_empty() {
+        panic!("At least one sample is required to compute CRI");
+    }
+
+    let cmf = &*CIE_1931_2_DEGREE;
+    let (cct, duv) = source_cct(test);
+    let reference = cri_reference_illuminant(cct);
+
+    let (test_white, test_samples) = sample_xyz(test, samples, cmf);
+    let (ref_white, ref_samples) = sample_xyz(&reference, samples, cmf);
+
+    let (u_k, v_k) = xyz_to_uv(test_white);
+    let (u_r, v_r) = xyz_to_uv(ref_white);
+    let (c_k, d_k) = von_kries_cd(u_k, v_k);
+    let (c_r, d_r) = von_kries_cd(u_r, v_r);
+
+    let ri: Vec<f64> = test_samples
+        .iter()
+        .zip(ref_samples.iter())
+        .map(|(&t, &r)| {
+            let (u, v) = xyz_to_uv(t);
+            let (c, d) = von_kries_cd(u, v);
+            let c = c_r / c_k * c;
+            let d = d_r / d_k * d;
+            let den = 16.518 + 1.481 * c - d;
+            let u_a = (10.872 + 0.404 * c - 4.0 * d) / den;
+            let v_a = 5.520 / den;
+
+            let (u, v) = xyz_to_uv(r);
+            let e_t = uvw(t, u_a, v_a, u_r, v_r);
+            let e_r = uvw(r, u, v, u_r, v_r);
+            let de = e_t
+                .iter()
+                .zip(e_r.iter())
+                .map(|(a, b)| (a - b) * (a - b))
+                .sum::<f64>()
+                .sqrt();
+
+            100.0 - 4.6 * de
+        })
+        .collect();
+
+    let n = ri.len().min(8);
+    let ra = ri[..n].iter().sum::<f64>() / n as f64;
+
+    ColorRenderingIndex { cct, duv, ra, ri }
+}
+
+/// Returns the CAM02-UCS coordinates of each of `samples` lit by
+/// `illuminant` under the TM-30-20 viewing conditions
+fn tm30_jab(illuminant: &VSPD, samples: &[VSPD]) -> Vec<Jab<f64>> {
+    let (white, xyz) = sample_xyz(illuminant, samples, &CIE_1964_10_DEGREE);
+    let mut vc =
+        ViewingConditions::new(white, 100.0, 20.0, Surround::average());
+    vc.discount_illuminant = true;
+    let space = UniformSpace::ucs();
+
+    xyz.into_iter()
+        .map(|c| {
+            let a = xyz_to_ciecam02(c, &vc);
+            jmh_to_jab(a.J, a.M, a.h, &space)
+        })
+        .collect()
+}
+
+/// Convert an average colour difference to the TM-30-20 fidelity scale
+fn tm30_fidelity(de: f64) -> f64 {
+    10.0 * (((100.0 - 6.73 * de) / 10.0).exp() + 1.0).ln()
+}
+
+/// Compute the IES TM-30-20 fidelity and gamut indices of the light source
+/// `test` for the reflectance `samples`.
+///
+/// Each sample is lit by `test` and by the reference illuminant of the same
+/// CCT given by [tm30_reference_illuminant], and the colours are compared in
+/// CAM02-UCS using the CIE 1964 10° observer. Rf is the fidelity given by
+/// the mean colour difference, and Rg is the ratio of the areas enclosed by
+/// the mean test and reference colours in each of [TM30_HUE_BINS] hue bins,
+/// where the bins are assigned by reference hue. Bins that contain no
+/// samples are left out of the gamut area.
+///
+/// The 99 IES colour evaluation samples give the standard indices.
+/// # Panics
+/// Panics if `samples` is empty, or if the CCT of `test` is above 25000K
+pub fn tm30_with_samples(test: &VSPD, samples: &[VSPD]) -> Tm30 {
+    if samples.is_empty() {
+        panic!("At least one sample is required to compute TM-30");
+    }
+
+    let (cct, duv) = source_cct(test);
+    let reference = tm30_reference_illuminant(cct);
+
+    let test_jab = tm30_jab(test, samples);
+    let ref_jab = tm30_jab(&reference, samples);
+
+    let de: Vec<f64> = test_jab
+        .iter()
+        .zip(ref_jab.iter())
+        .map(|(t, r)| {
+            ((t.J - r.J) * (t.J - r.J)
+                + (t.a - r.a) * (t.a - r.a)
+                + (t.b - r.b) * (t.b - r.b))
+                .sqrt()
+        })
+        .collect();
+
+    let rf = tm30_fidelity(de.iter().sum::<f64>() / de.len() as f64);
+    let rf_i = de.iter().map(|&e| tm30_fidelity(e)).collect();
+
+    // average a' and b' of the test and reference colours in each bin
+    let mut bins = [(0usize, 0.0, 0.0, 0.0, 0.0); TM30_HUE_BINS];
+    for (t, r) in test_jab.iter().zip(ref_jab.iter()) {
+        let h = atan2(r.b, r.a).rem_euclid(2.0 * PI);
+        let i = ((h / (2.0 * PI) * TM30_HUE_BINS as f64) as usize)
+            .min(TM30_HUE_BINS - 1);
+        let bin = &mut bins[i];
+        bin.0 += 1;
+        bin.1 += t.a;
+        bin.2 += t.b;
+        bin.3 += r.a;
+        bin.4 += r.b;
+    }
+    let (test_gamut, ref_gamut): (Vec<_>, Vec<_>) = bins
+        .iter()
+        .filter(|b| b.0 > 0)
+        .map(|b| {
+            let n = b.0 as f64;
+            ((b.1 / n, b.2 / n), (b.3 / n, b.4 / n))
+        })
+        .unzip();
+    let rg = 100.0 * polygon_area(&test_gamut) / polygon_area(&ref_gamut);
+
+    Tm30 {
+        cct,
+        duv,
+        rf,
+        rg,
+        rf_i,
+    }
+}
+
+/// Returns the area of the polygon with the given vertices
+fn polygon_area(p: &[(f64, f64)]) -> f64 {
+    let n = p.len();
+    (0..n)
+        .map(|i| {
+            let (x0, y0) = p[i];
+            let (x1, y1) = p[(i + 1) % n];
+            x0 * y1 - x1 * y0
+        })
+        .sum::<f64>()
+        .abs()
+        * 0.5
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colorchecker;
+    use crate::illuminant::spd::{
+        A, D65, F1, F11, F12, F2, F3, F4, F5, F6, F7, F8, F9,
+    };
+    use float_cmp::{ApproxEq, F64Margin};
+
+    /// The chromatic patches of the ColorChecker
+    fn samples() -> Vec<VSPD> {
+        colorchecker::NAMES[..18]
+            .iter()
+            .map(|n| colorchecker::SPECTRAL[*n].clone())
+            .collect()
+    }
+
+    #[test]
+    fn reference_illuminants() {
+        // TM-30 and CIE 13.3 agree outside the blend region
+        assert!(
+            tm30_reference_illuminant(3000.0)
+                == cri_reference_illuminant(3000.0)
+        );
+        assert!(
+            tm30_reference_illuminant(6500.0)
+                == cri_reference_illuminant(6500.0)
+        );
+
+        // the blend has the requested CCT
+        let (cct, _) = source_cct(&tm30_reference_illuminant(4500.0));
+        assert!((cct - 4500.0).abs() < 10.0);
+    }
+
+    #[test]
+    fn cri_samples() {
+        let samples = samples();
+
+        // a Planckian source is its own reference
+        let bb = blackbody(2856.0, SpdShape::new(360.0, 830.0, 5.0));
+        let c = cri_with_samples(&bb, &samples);
+        assert!((c.cct - 2856.0).abs() < 1.0);
+        assert!(c.ra.approx_eq(100.0, F64Margin::default().epsilon(0.01)));
+        assert_eq!(c.ri.len(), samples.len());
+        for r in &c.ri {
+            assert!(r.approx_eq(100.0, F64Margin::default().epsilon(0.01)));
+        }
+
+        // illuminant A and D65 are close to their references
+        assert!(cri_with_samples(&A, &samples).ra > 99.0);
+        assert!(cri_with_samples(&D65, &samples).ra > 99.0);
+    }
+
+    #[test]
+    fn cri_published() {
+        // Ra of the CIE F illuminants from CIE 15:2004
+        for (spd, ra) in [
+            (&*F1, 76.0),
+            (&*F2, 64.0),
+            (&*F3, 57.0),
+            (&*F4, 51.0),
+            (&*F5, 72.0),
+            (&*F6, 59.0),
+            (&*F7, 90.0),
+            (&*F8, 95.0),
+            (&*F9, 90.0),
+            (&*F11, 83.0),
+            (&*F12, 83.0),
+        ]
+        .iter()
+        {
+            let c = cri(spd);
+            assert!((c.ra - ra).abs() < 0.6, "{} != {}", c.ra, ra);
+        }
+
+        // R1-R8 of F2 from CIE 13.3
+        let f2 = cri(&F2);
+        assert_eq!(f2.ri.len(), 8);
+        for (r, r_p) in f2
+            .ri
+            .iter()
+            .zip([56.0, 77.0, 90.0, 57.0, 59.0, 67.0, 74.0, 33.0].iter())
+        {
+            assert!((r - r_p).abs() < 0.6, "{} != {}", r, r_p);
+        }
+
+        assert!(cri(&A).ra > 99.9);
+        assert!(cri(&D65).ra > 99.9);
+    }
+
+    #[test]
+    fn tm30_samples() {
+        let samples = samples();
+
+        let bb = blackbody(3000.0, SpdShape::new(360.0, 830.0, 5.0));
+        let t = tm30_with_samples(&bb, &samples);
+        assert!(t.rf.approx_eq(100.0, F64Margin::default().epsilon(0.01)));
+        assert!(t.rg.approx_eq(100.0, F64Margin::default().epsilon(0.01)));
+
+        let f2 = tm30_with_samples(&F2, &samples);
+        assert!(f2.rf > 50.0 && f2.rf < 90.0);
+        assert!(f2.rg < 100.0);
+        assert_eq!(f2.rf_i.len(), samples.len());
+        assert!(f2.rf_i.iter().all(|&r| r > 0.0 && r < 100.0));
+    }
+}
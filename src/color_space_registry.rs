@@ -0,0 +1,425 @@
+//! A registry of RGB color spaces that can be looked up by name.
+//!
+//! All the models in [model_f64](crate::color_space_rgb::model_f64) are
+//! registered under a canonical name and a set of aliases. Lookup is
+//! case-insensitive. Applications can add their own spaces with [register].
+//!
+//! ```
+//! use colorspace::*;
+//! let srgb = color_space_registry::get("sRGB").unwrap();
+//! let aces_cg = color_space_registry::get("lin_ap1").unwrap();
+//! let mtx = rgb_to_rgb_matrix(srgb, aces_cg);
+//! ```
+use super::chromaticity::XYYf64;
+use super::color_space_rgb::{
+    color_space_models, model_f64::*, ColorSpaceRGB, TransferCurve,
+};
+use lazy_static::lazy_static;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::RwLock;
+
+color_space_models! {
+    /// Registry entries that aren't in [model_f64]
+    mod extra_f64;
+    mod extra_f32;
+
+    /// Linear ITU-R Rec. BT.709, i.e. sRGB primaries with no transfer
+    /// function
+    LIN_REC709 = ColorSpaceRGB::new(
+        ITUR_BT709.red,
+        ITUR_BT709.green,
+        ITUR_BT709.blue,
+        ITUR_BT709.white,
+        TransferCurve::Linear,
+    );
+
+    /// Linear ITU-R Rec. BT.2020
+    LIN_REC2020 = ColorSpaceRGB::new(
+        ITUR_BT2020.red,
+        ITUR_BT2020.green,
+        ITUR_BT2020.blue,
+        ITUR_BT2020.white,
+        TransferCurve::Linear,
+    );
+
+    /// CIE XYZ as an RGB space, with equal-energy white and XYZ as the
+    /// identity
+    CIE_XYZ = ColorSpaceRGB::new(
+        XYYf64 { x: 1.0, y: 0.0, Y: 1.0 },
+        XYYf64 { x: 0.0, y: 1.0, Y: 1.0 },
+        XYYf64 { x: 0.0, y: 0.0, Y: 1.0 },
+        XYYf64 {
+            x: 1.0 / 3.0,
+            y: 1.0 / 3.0,
+            Y: 1.0,
+        },
+        TransferCurve::Linear,
+    );
+}
+
+/// Every built-in color space, in each precision
+mod builtin_f64 {
+    pub use super::extra_f64::*;
+    pub use crate::color_space_rgb::model_f64::*;
+}
+
+mod builtin_f32 {
+    pub use super::extra_f32::*;
+    pub use crate::color_space_rgb::model_f32::*;
+}
+
+/// Builds the table of built-in entries, naming each model once and taking
+/// both precisions of it
+macro_rules! builtin {
+    ($(($name:expr, [$($alias:expr),*], $model:ident $(,)?)),* $(,)?) => {
+        &[$((
+            $name,
+            &[$($alias),*],
+            &*builtin_f64::$model,
+            &*builtin_f32::$model,
+        )),*]
+    };
+}
+
+lazy_static! {
+    static ref REGISTRY: RwLock<Registry> = RwLock::new(Registry::builtin());
+}
+
+/// Errors returned when registering a color space
+#[derive(Clone, Debug, PartialEq)]
+pub enum RegistryError {
+    /// The name or alias is already registered, or is given more than once
+    NameTaken(String),
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegistryError::NameTaken(name) => {
+                write!(f, "Color space name \"{}\" is already registered", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+struct Entry {
+    name: String,
+    aliases: Vec<String>,
+    color_space: &'static ColorSpaceRGB<f64>,
+    color_space_f32: &'static ColorSpaceRGB<f32>,
+}
+
+struct Registry {
+    entries: Vec<Entry>,
+    lookup: HashMap<String, usize>,
+}
+
+impl Registry {
+    fn builtin() -> Registry {
+        let mut registry = Registry {
+            entries: Vec::new(),
+            lookup: HashMap::new(),
+        };
+
+        #[allow(clippy::type_complexity)]
+        let builtin: &[(
+            &str,
+            &[&str],
+            &'static ColorSpaceRGB<f64>,
+            &'static ColorSpaceRGB<f32>,
+        )] = builtin![
+            ("sRGB", ["srgb_texture", "srgb_tx"], SRGB),
+            ("sRGB (derived)", ["srgb_drv"], SRGB_DRV),
+            ("lin_rec709", ["Linear Rec.709", "lin_srgb"], LIN_REC709),
+            ("Rec.709", ["ITU-R BT.709", "BT.709", "rec709"], ITUR_BT709),
+            ("lin_rec2020", ["Linear Rec.2020"], LIN_REC2020),
+            (
+                "Rec.2020",
+                ["ITU-R BT.2020", "BT.2020", "rec2020"],
+                ITUR_BT2020,
+            ),
+            (
+                "Rec.2100 PQ",
+                ["ITU-R BT.2100 PQ", "BT.2100 PQ", "rec2100_pq"],
+                ITUR_BT2100_PQ,
+            ),
+            (
+                "Rec.2100 HLG",
+                ["ITU-R BT.2100 HLG", "BT.2100 HLG", "rec2100_hlg"],
+                ITUR_BT2100_HLG,
+            ),
+            ("DCI-P3", ["P3-DCI", "dcip3"], DCI_P3),
+            ("P3-D65", ["DCI-P3 D65", "p3d65"], DCI_P3_D65),
+            ("P3-D65 PQ", ["p3d65_pq"], DCI_P3_D65_PQ),
+            ("Display P3", ["displayp3"], DISPLAY_P3),
+            ("DCI-P3+", ["dcip3p"], DCI_P3_P),
+            ("ACES2065-1", ["ACES", "lin_ap0"], ACES),
+            ("ACEScg", ["lin_ap1"], ACES_CG),
+            ("ACEScc", [], ACES_CC),
+            ("ACEScct", [], ACES_CCT),
+            ("ACESproxy", [], ACES_PROXY),
+            (
+                "Adobe RGB (1998)",
+                ["Adobe RGB", "adobergb"],
+                ADOBE_RGB_1998,
+            ),
+            (
+                "ALEXA Wide Gamut",
+                ["ARRI Wide Gamut 3", "awg3"],
+                ALEXA_WIDE_GAMUT,
+            ),
+            ("ARRI Wide Gamut 4", ["awg4"], ALEXA_WIDE_GAMUT_4),
+            ("REDWideGamutRGB", ["rwg"], RED_WIDE_GAMUT_RGB),
+            ("REDcolor", [], RED_COLOR),
+            ("REDcolor2", [], RED_COLOR2),
+            ("REDcolor3", [], RED_COLOR3),
+            ("REDcolor4", [], RED_COLOR4),
+            ("DRAGONcolor", [], DRAGON_COLOR),
+            ("DRAGONcolor2", [], DRAGON_COLOR2),
+            ("S-Gamut3", ["sgamut3"], S_GAMUT3),
+            ("S-Gamut3.Cine", ["sgamut3cine"], S_GAMUT3_CINE),
+            ("V-Gamut", ["vgamut"], V_GAMUT),
+            ("Cinema Gamut C-Log2", ["canon_clog2"], CINEMA_GAMUT_CLOG2),
+            ("Cinema Gamut C-Log3", ["canon_clog3"], CINEMA_GAMUT_CLOG3),
+            ("Blackmagic Wide Gamut", ["bmdwg"], BLACKMAGIC_WIDE_GAMUT),
+            ("ProPhoto RGB", ["ROMM RGB"], PRO_PHOTO_RGB),
+            ("Beta RGB", [], BETA_RGB),
+            ("Sharp RGB", [], SHARP_RGB),
+            ("ECI RGB v2", [], ECI_RGB_V2),
+            ("Wide Gamut RGB", ["Adobe Wide Gamut RGB"], WIDE_GAMUT_RGB),
+            ("Apple RGB", [], APPLE_RGB),
+            ("NTSC (1953)", ["NTSC 1953"], NTSC_1953),
+            ("SMPTE-C", ["SMPTE C"], SMPTE_C),
+            ("PAL/SECAM", ["PAL", "SECAM"], PAL_SECAM),
+            ("XYZ", ["CIE XYZ", "lin_ciexyz"], CIE_XYZ),
+        ];
+
+        for (name, aliases, color_space, color_space_f32) in builtin.iter() {
+            registry
+                .insert(name, aliases, color_space, color_space_f32)
+                .unwrap();
+        }
+
+        registry
+    }
+
+    /// Checks that none of `name` and `aliases` is already taken, or given
+    /// more than once
+    fn check(&self, name: &str, aliases: &[&str]) -> Result<(), RegistryError> {
+        let keys: Vec<&str> = std::iter::once(name)
+            .chain(aliases.iter().cloned())
+            .collect();
+        for (i, key) in keys.iter().enumerate() {
+            let key = key.to_lowercase();
+            if self.lookup.contains_key(&key)
+                || keys[..i].iter().any(|k| k.to_lowercase() == key)
+            {
+                return Err(RegistryError::NameTaken(keys[i].to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds an entry, or returns an error naming the first of `name` and
+    /// `aliases` that is already taken
+    fn insert(
+        &mut self,
+        name: &str,
+        aliases: &[&str],
+        color_space: &'static ColorSpaceRGB<f64>,
+        color_space_f32: &'static ColorSpaceRGB<f32>,
+    ) -> Result<(), RegistryError> {
+        self.check(name, aliases)?;
+
+        let keys = std::iter::once(name).chain(aliases.iter().cloned());
+        let index = self.entries.len();
+        for key in keys {
+            self.lookup.insert(key.to_lowercase(), index);
+        }
+        self.entries.push(Entry {
+            name: name.to_string(),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+            color_space,
+            color_space_f32,
+        });
+        Ok(())
+    }
+}
+
+/// Look up a color space by its canonical name or one of its aliases. The
+/// lookup is case-insensitive.
+pub fn get(name: &str) -> Option<&'static ColorSpaceRGB<f64>> {
+    let registry = REGISTRY.read().unwrap();
+    registry
+        .lookup
+        .get(&name.to_lowercase())
+        .map(|i| registry.entries[*i].color_space)
+}
+
+/// Look up the 32-bit version of a color space by its canonical name or one
+/// of its aliases. Every registered space, including those added with
+/// [register], is available in both precisions.
+pub fn get_f32(name: &str) -> Option<&'static ColorSpaceRGB<f32>> {
+    let registry = REGISTRY.read().unwrap();
+    registry
+        .lookup
+        .get(&name.to_lowercase())
+        .map(|i| registry.entries[*i].color_space_f32)
+}
+
+/// Returns the canonical names of all registered color spaces, in the order
+/// they were registered.
+pub fn names() -> Vec<String> {
+    let registry = REGISTRY.read().unwrap();
+    registry.entries.iter().map(|e| e.name.clone()).collect()
+}
+
+/// Returns the canonical name of the color space registered under `name`,
+/// which may be an alias.
+pub fn canonical_name(name: &str) -> Option<String> {
+    let registry = REGISTRY.read().unwrap();
+    registry
+        .lookup
+        .get(&name.to_lowercase())
+        .map(|i| registry.entries[*i].name.clone())
+}
+
+/// Returns the aliases of the color space registered under `name`.
+pub fn aliases(name: &str) -> Option<Vec<String>> {
+    let registry = REGISTRY.read().unwrap();
+    registry
+        .lookup
+        .get(&name.to_lowercase())
+        .map(|i| registry.entries[*i].aliases.clone())
+}
+
+/// Register a custom color space under `name` and `aliases`. The color
+/// space lives for the rest of the program and the returned reference can
+/// be used directly.
+///
+/// ```
+/// use colorspace::*;
+/// let cs = ColorSpaceRGB::<f64>::new(
+///     XYYf64 { x: 0.680, y: 0.320, Y: 1.0 },
+///     XYYf64 { x: 0.265, y: 0.690, Y: 1.0 },
+///     XYYf64 { x: 0.150, y: 0.060, Y: 1.0 },
+///     XYYf64 { x: 0.3127, y: 0.3290, Y: 1.0 },
+///     TransferCurve::Linear,
+/// );
+/// color_space_registry::register("lin_p3d65", &["Linear P3-D65"], cs)
+///     .unwrap();
+/// assert!(color_space_registry::get("linear p3-d65").is_some());
+/// ```
+///
+/// Returns [RegistryError::NameTaken] if `name` or any of `aliases` is
+/// already registered, in which case the registry is left unchanged.
+pub fn register(
+    name: &str,
+    aliases: &[&str],
+    color_space: ColorSpaceRGB<f64>,
+) -> Result<&'static ColorSpaceRGB<f64>, RegistryError> {
+    let mut registry = REGISTRY.write().unwrap();
+    // check before leaking so a rejected color space is dropped
+    registry.check(name, aliases)?;
+    let color_space_f32: &'static ColorSpaceRGB<f32> =
+        Box::leak(Box::new((&color_space).into()));
+    let color_space: &'static ColorSpaceRGB<f64> =
+        Box::leak(Box::new(color_space));
+    registry.insert(name, aliases, color_space, color_space_f32)?;
+    Ok(color_space)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color_space_rgb::model_f32;
+
+    #[test]
+    fn lookup() {
+        assert!(std::ptr::eq(get("sRGB").unwrap(), &*SRGB));
+        assert!(std::ptr::eq(get("SRGB").unwrap(), &*SRGB));
+        assert!(std::ptr::eq(get("ACEScg").unwrap(), &*ACES_CG));
+        assert!(std::ptr::eq(get("lin_ap1").unwrap(), &*ACES_CG));
+        assert!(std::ptr::eq(get("Rec.2020").unwrap(), &*ITUR_BT2020));
+        assert!(get("no such space").is_none());
+        assert!(std::ptr::eq(
+            get_f32("srgb_drv").unwrap(),
+            &*model_f32::SRGB_DRV
+        ));
+        assert!(get_f32("no such space").is_none());
+
+        let lin_rec709 = get("lin_rec709").unwrap();
+        assert_eq!(lin_rec709.xf_xyz_to_rgb.x, ITUR_BT709.xf_xyz_to_rgb.x);
+        let c = crate::rgb::rgbf(0.5, 0.25, 0.125);
+        assert_eq!(lin_rec709.encode(c), c);
+
+        assert_eq!(canonical_name("lin_ap0").unwrap(), "ACES2065-1");
+        assert_eq!(aliases("ACES2065-1").unwrap(), vec!["ACES", "lin_ap0"]);
+        assert_eq!(names()[0], "sRGB");
+
+        // every canonical name and alias resolves to its own entry
+        for name in names() {
+            let cs = get(&name).unwrap();
+            for alias in aliases(&name).unwrap() {
+                assert!(std::ptr::eq(get(&alias).unwrap(), cs));
+            }
+        }
+
+        let xyz = get("XYZ").unwrap();
+        for (i, x) in xyz.xf_xyz_to_rgb.x.iter().enumerate() {
+            let identity = if i % 4 == 0 { 1.0 } else { 0.0 };
+            assert!((x - identity).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn register_custom() {
+        let cs = ColorSpaceRGB::new(
+            ACES_CG.red,
+            ACES_CG.green,
+            ACES_CG.blue,
+            ACES_CG.white,
+            TransferCurve::Srgb,
+        );
+        let cs = register("ACEScg sRGB curve", &["acescg_srgb"], cs).unwrap();
+        assert!(std::ptr::eq(get("acescg_srgb").unwrap(), cs));
+        assert_eq!(
+            *get_f32("ACEScg sRGB curve").unwrap(),
+            ColorSpaceRGB::<f32>::from(cs)
+        );
+        assert!(names().contains(&"ACEScg sRGB curve".to_string()));
+    }
+
+    #[test]
+    fn register_duplicate() {
+        let cs = || {
+            ColorSpaceRGB::new(
+                SRGB.red,
+                SRGB.green,
+                SRGB.blue,
+                SRGB.white,
+                TransferCurve::Srgb,
+            )
+        };
+        assert_eq!(
+            register("my srgb", &["srgb"], cs()),
+            Err(RegistryError::NameTaken("srgb".to_string()))
+        );
+        assert_eq!(
+            register("another srgb", &["rec709"], cs()),
+            Err(RegistryError::NameTaken("rec709".to_string()))
+        );
+        assert_eq!(
+            register("srgb twice", &["SRGB Twice"], cs()),
+            Err(RegistryError::NameTaken("SRGB Twice".to_string()))
+        );
+        assert!(get("my srgb").is_none());
+        assert!(get("another srgb").is_none());
+        assert!(get("srgb twice").is_none());
+        assert!(std::ptr::eq(get("rec709").unwrap(), &*ITUR_BT709));
+    }
+}
@@ -4,9 +4,10 @@
 //! and [model_f64] submodules.
 #![allow(clippy::excessive_precision, clippy::unreadable_literal)]
 use super::chromaticity::*;
-use super::math::{M3f32, M3f64, Matrix33, Real};
-use super::rgb::{RGBf, RGBf32, RGBf64};
+use super::math::{M3f64, Matrix33, Real};
+use super::rgb::RGBf;
 use lazy_static::lazy_static;
+use std::sync::Arc;
 
 use numeric_literals::replace_float_literals;
 
@@ -146,148 +147,1514 @@ pub mod encode {
             b: alexa_logc_v3_t(x.b),
         }
     }
+
+    /// RED Log3G10 encoding
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn red_log3g10_t<T>(x: T) -> T
+    where
+        T: Real,
+    {
+        let a = 0.224282;
+        let b = 155.975327;
+        let c = 0.01;
+        let g = 15.1927;
+        let x = x + c;
+        if x < 0.0 {
+            x * g
+        } else {
+            a * (x * b + 1.0).log10()
+        }
+    }
+
+    #[inline]
+    pub fn red_log3g10<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: red_log3g10_t(x.r),
+            g: red_log3g10_t(x.g),
+            b: red_log3g10_t(x.b),
+        }
+    }
+
+    /// RED REDLogFilm encoding, which is the Cineon curve with a black
+    /// offset
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn red_logfilm_t<T>(x: T) -> T
+    where
+        T: Real,
+    {
+        let black_offset = 10.0.powf((95.0 - 685.0) / 300.0);
+        (685.0 + 300.0 * (x * (1.0 - black_offset) + black_offset).log10())
+            / 1023.0
+    }
+
+    #[inline]
+    pub fn red_logfilm<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: red_logfilm_t(x.r),
+            g: red_logfilm_t(x.g),
+            b: red_logfilm_t(x.b),
+        }
+    }
+
+    /// Sony S-Log3 encoding
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn sony_slog3_t<T>(x: T) -> T
+    where
+        T: Real,
+    {
+        if x >= 0.01125 {
+            (420.0 + ((x + 0.01) / (0.18 + 0.01)).log10() * 261.5) / 1023.0
+        } else {
+            (x * (171.2102946929 - 95.0) / 0.01125 + 95.0) / 1023.0
+        }
+    }
+
+    #[inline]
+    pub fn sony_slog3<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: sony_slog3_t(x.r),
+            g: sony_slog3_t(x.g),
+            b: sony_slog3_t(x.b),
+        }
+    }
+
+    /// Panasonic V-Log encoding
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn panasonic_vlog_t<T>(x: T) -> T
+    where
+        T: Real,
+    {
+        let b = 0.00873;
+        let c = 0.241514;
+        let d = 0.598206;
+        if x < 0.01 {
+            5.6 * x + 0.125
+        } else {
+            c * (x + b).log10() + d
+        }
+    }
+
+    #[inline]
+    pub fn panasonic_vlog<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: panasonic_vlog_t(x.r),
+            g: panasonic_vlog_t(x.g),
+            b: panasonic_vlog_t(x.b),
+        }
+    }
+
+    /// Canon Log 2 encoding. Linear values are scene reflectance, so that
+    /// 0.9 maps to the 90% white reference.
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn canon_clog2_t<T>(x: T) -> T
+    where
+        T: Real,
+    {
+        let x = x / 0.9;
+        if x < 0.0 {
+            -0.24136077 * (-x * 87.09937546 + 1.0).log10() + 0.092864125
+        } else {
+            0.24136077 * (x * 87.09937546 + 1.0).log10() + 0.092864125
+        }
+    }
+
+    #[inline]
+    pub fn canon_clog2<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: canon_clog2_t(x.r),
+            g: canon_clog2_t(x.g),
+            b: canon_clog2_t(x.b),
+        }
+    }
+
+    /// Canon Log 3 encoding. Linear values are scene reflectance, so that
+    /// 0.9 maps to the 90% white reference.
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn canon_clog3_t<T>(x: T) -> T
+    where
+        T: Real,
+    {
+        let x = x / 0.9;
+        if x < -0.014 {
+            -0.36726845 * (-x * 14.98325 + 1.0).log10() + 0.12783901
+        } else if x <= 0.014 {
+            1.9754798 * x + 0.12512219
+        } else {
+            0.36726845 * (x * 14.98325 + 1.0).log10() + 0.12240537
+        }
+    }
+
+    #[inline]
+    pub fn canon_clog3<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: canon_clog3_t(x.r),
+            g: canon_clog3_t(x.g),
+            b: canon_clog3_t(x.b),
+        }
+    }
+
+    /// Blackmagic Film Generation 5 encoding
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn blackmagic_film_gen5_t<T>(x: T) -> T
+    where
+        T: Real,
+    {
+        let a = 0.08692876065491224;
+        let b = 0.005494072432257808;
+        let c = 0.5300133392291939;
+        let d = 8.283605932402494;
+        let e = 0.09246575342465753;
+        if x < 0.005 {
+            d * x + e
+        } else {
+            a * (x + b).ln() + c
+        }
+    }
+
+    #[inline]
+    pub fn blackmagic_film_gen5<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: blackmagic_film_gen5_t(x.r),
+            g: blackmagic_film_gen5_t(x.g),
+            b: blackmagic_film_gen5_t(x.b),
+        }
+    }
+
+    /// ARRI LogC4 encoding
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn alexa_logc_v4_t<T>(x: T) -> T
+    where
+        T: Real,
+    {
+        let a = (2.0.powi(18) - 16.0) / 117.45;
+        let b = (1023.0 - 95.0) / 1023.0;
+        let c = 95.0 / 1023.0;
+        let s = (7.0 * 2.0.ln() * 2.0.powf(7.0 - 14.0 * c / b)) / (a * b);
+        let t = (2.0.powf(14.0 * (-c / b) + 6.0) - 64.0) / a;
+        if x >= t {
+            ((a * x + 64.0).log2() - 6.0) / 14.0 * b + c
+        } else {
+            (x - t) / s
+        }
+    }
+
+    #[inline]
+    pub fn alexa_logc_v4<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: alexa_logc_v4_t(x.r),
+            g: alexa_logc_v4_t(x.g),
+            b: alexa_logc_v4_t(x.b),
+        }
+    }
+
+    /// ProPhoto RGB (ROMM RGB) encoding
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn prophoto_t<T>(x: T) -> T
+    where
+        T: Real,
+    {
+        if x < 1.0 / 512.0 {
+            x * 16.0
+        } else {
+            x.powf(1.0 / 1.8)
+        }
+    }
+
+    #[inline]
+    pub fn prophoto<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: prophoto_t(x.r),
+            g: prophoto_t(x.g),
+            b: prophoto_t(x.b),
+        }
+    }
+
+    /// CIE L* encoding, as used by ECI RGB v2, normalised to [0, 1]
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn lstar_t<T>(x: T) -> T
+    where
+        T: Real,
+    {
+        if x <= 216.0 / 24389.0 {
+            x * 24389.0 / 2700.0
+        } else {
+            1.16 * x.powf(1.0 / 3.0) - 0.16
+        }
+    }
+
+    #[inline]
+    pub fn lstar<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: lstar_t(x.r),
+            g: lstar_t(x.g),
+            b: lstar_t(x.b),
+        }
+    }
+
+    /// ACEScc log encoding, as specified in Academy S-2014-003
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn acescc_t<T>(x: T) -> T
+    where
+        T: Real,
+    {
+        if x <= 0.0 {
+            (-16.0 + 9.72) / 17.52
+        } else if x < 2.0.powi(-15) {
+            ((2.0.powi(-16) + x * 0.5).log2() + 9.72) / 17.52
+        } else {
+            (x.log2() + 9.72) / 17.52
+        }
+    }
+
+    #[inline]
+    pub fn acescc<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: acescc_t(x.r),
+            g: acescc_t(x.g),
+            b: acescc_t(x.b),
+        }
+    }
+
+    /// ACEScct log encoding, as specified in Academy S-2016-001
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn acescct_t<T>(x: T) -> T
+    where
+        T: Real,
+    {
+        let x_brk = 0.0078125;
+        let a = 10.5402377416545;
+        let b = 0.0729055341958355;
+        if x <= x_brk {
+            a * x + b
+        } else {
+            (x.log2() + 9.72) / 17.52
+        }
+    }
+
+    #[inline]
+    pub fn acescct<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: acescct_t(x.r),
+            g: acescct_t(x.g),
+            b: acescct_t(x.b),
+        }
+    }
+
+    /// ACESproxy 10-bit log encoding, as specified in Academy S-2013-001.
+    /// The result is quantised to 10-bit code values and normalised to
+    /// [0, 1].
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn acesproxy_t<T>(x: T) -> T
+    where
+        T: Real,
+    {
+        let cv_min = 64.0;
+        let cv_max = 940.0;
+        let steps_per_stop = 50.0;
+        let mid_cv_offset = 425.0;
+        let mid_log_offset = 2.5;
+
+        let cv = if x > 2.0.powf(-9.72) {
+            ((x.log2() + mid_log_offset) * steps_per_stop + mid_cv_offset)
+                .round()
+                .max(cv_min)
+                .min(cv_max)
+        } else {
+            cv_min
+        };
+        cv / 1023.0
+    }
+
+    #[inline]
+    pub fn acesproxy<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: acesproxy_t(x.r),
+            g: acesproxy_t(x.g),
+            b: acesproxy_t(x.b),
+        }
+    }
+
+    /// SMPTE ST 2084 (PQ) inverse EOTF. `x` is display luminance normalised
+    /// so that 1.0 is 10000 cd/m².
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn pq_t<T>(x: T) -> T
+    where
+        T: Real,
+    {
+        let m1 = 2610.0 / 16384.0;
+        let m2 = 2523.0 / 4096.0 * 128.0;
+        let c1 = 3424.0 / 4096.0;
+        let c2 = 2413.0 / 4096.0 * 32.0;
+        let c3 = 2392.0 / 4096.0 * 32.0;
+
+        let y = x.max(0.0).powf(m1);
+        ((c1 + c2 * y) / (1.0 + c3 * y)).powf(m2)
+    }
+
+    #[inline]
+    pub fn pq<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: pq_t(x.r),
+            g: pq_t(x.g),
+            b: pq_t(x.b),
+        }
+    }
+
+    /// ARIB STD-B67 (HLG) OETF, taking normalised scene-linear light.
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn hlg_t<T>(x: T) -> T
+    where
+        T: Real,
+    {
+        let a = 0.17883277;
+        let b = 1.0 - 4.0 * a;
+        let c = 0.5 - a * (4.0 * a).ln();
+        if x <= 1.0 / 12.0 {
+            (3.0 * x.max(0.0)).sqrt()
+        } else {
+            a * (12.0 * x - b).ln() + c
+        }
+    }
+
+    #[inline]
+    pub fn hlg<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: hlg_t(x.r),
+            g: hlg_t(x.g),
+            b: hlg_t(x.b),
+        }
+    }
+
+    /// HLG inverse EOTF: applies the inverse OOTF with the given system gamma
+    /// to normalised display-linear light, then the OETF.
+    #[inline]
+    pub fn hlg_inverse_eotf<T>(x: RGBf<T>, gamma: T) -> RGBf<T>
+    where
+        T: Real,
+    {
+        hlg(super::hlg_inverse_ootf(x, gamma))
+    }
+
+    /// Inverse of the ITU-R BT.1886 reference EOTF for a display with white
+    /// luminance `l_w` and black luminance `l_b`, taking luminance in the same
+    /// units.
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn bt1886_t<T>(x: T, l_w: T, l_b: T) -> T
+    where
+        T: Real,
+    {
+        let w = l_w.powf(1.0 / 2.4);
+        let k = l_b.powf(1.0 / 2.4);
+        let a = (w - k).powf(2.4);
+        let b = k / (w - k);
+        (x.max(0.0) / a).powf(1.0 / 2.4) - b
+    }
 }
 
-pub mod decode {
+pub mod decode {
+
+    use crate::math::Real;
+    use crate::rgb::RGBf;
+    use numeric_literals::replace_float_literals;
+
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn srgb_t<T>(f: T) -> T
+    where
+        T: Real,
+    {
+        if f <= 0.040449936 {
+            f / 12.92
+        } else {
+            ((f + 0.055) / 1.055).powf(2.4)
+        }
+    }
+
+    #[inline]
+    pub fn srgb<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: srgb_t(x.r),
+            g: srgb_t(x.g),
+            b: srgb_t(x.b),
+        }
+    }
+
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn bt709_t<T>(f: T) -> T
+    where
+        T: Real,
+    {
+        if f <= 0.018 * 4.5 {
+            f / 4.5
+        } else {
+            ((f + 0.099) / 1.099).powf(1.0 / 0.45)
+        }
+    }
+
+    #[inline]
+    pub fn bt709<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: bt709_t(x.r),
+            g: bt709_t(x.g),
+            b: bt709_t(x.b),
+        }
+    }
+
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn bt2020_t<T>(f: T) -> T
+    where
+        T: Real,
+    {
+        let alpha = 1.099;
+        let beta = 0.018;
+        if f < beta * 4.5 {
+            f / 4.5
+        } else {
+            ((f + (alpha - 1.0)) / alpha).powf(1.0 / 0.45)
+        }
+    }
+
+    #[inline]
+    pub fn bt2020<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: bt2020_t(x.r),
+            g: bt2020_t(x.g),
+            b: bt2020_t(x.b),
+        }
+    }
+
+    #[inline]
+    pub fn linear_t<T>(x: T) -> T
+    where
+        T: Real,
+    {
+        x
+    }
+
+    #[inline]
+    pub fn linear<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: linear_t(x.r),
+            g: linear_t(x.g),
+            b: linear_t(x.b),
+        }
+    }
+
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn alexa_logc_v3_t<T>(t: T) -> T
+    where
+        T: Real,
+    {
+        // using parameters for EI 800
+        let a = 5.555556;
+        let b = 0.052272;
+        let c = 0.247190;
+        let d = 0.385537;
+        let e = 5.367655;
+        let f = 0.092809;
+        let ecf = 0.149658;
+        if t > ecf {
+            (10.0.powf((t - d) / c) - b) / a
+        } else {
+            (t - f) / e
+        }
+    }
+
+    #[inline]
+    pub fn alexa_logc_v3<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: alexa_logc_v3_t(x.r),
+            g: alexa_logc_v3_t(x.g),
+            b: alexa_logc_v3_t(x.b),
+        }
+    }
+
+    /// Inverse of the RED Log3G10 encoding
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn red_log3g10_t<T>(t: T) -> T
+    where
+        T: Real,
+    {
+        let a = 0.224282;
+        let b = 155.975327;
+        let c = 0.01;
+        let g = 15.1927;
+        if t < 0.0 {
+            t / g - c
+        } else {
+            (10.0.powf(t / a) - 1.0) / b - c
+        }
+    }
+
+    #[inline]
+    pub fn red_log3g10<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: red_log3g10_t(x.r),
+            g: red_log3g10_t(x.g),
+            b: red_log3g10_t(x.b),
+        }
+    }
+
+    /// Inverse of the RED REDLogFilm encoding
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn red_logfilm_t<T>(t: T) -> T
+    where
+        T: Real,
+    {
+        let black_offset = 10.0.powf((95.0 - 685.0) / 300.0);
+        (10.0.powf((1023.0 * t - 685.0) / 300.0) - black_offset)
+            / (1.0 - black_offset)
+    }
+
+    #[inline]
+    pub fn red_logfilm<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: red_logfilm_t(x.r),
+            g: red_logfilm_t(x.g),
+            b: red_logfilm_t(x.b),
+        }
+    }
+
+    /// Inverse of the Sony S-Log3 encoding
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn sony_slog3_t<T>(t: T) -> T
+    where
+        T: Real,
+    {
+        if t >= 171.2102946929 / 1023.0 {
+            10.0.powf((t * 1023.0 - 420.0) / 261.5) * (0.18 + 0.01) - 0.01
+        } else {
+            (t * 1023.0 - 95.0) * 0.01125 / (171.2102946929 - 95.0)
+        }
+    }
+
+    #[inline]
+    pub fn sony_slog3<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: sony_slog3_t(x.r),
+            g: sony_slog3_t(x.g),
+            b: sony_slog3_t(x.b),
+        }
+    }
+
+    /// Inverse of the Panasonic V-Log encoding
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn panasonic_vlog_t<T>(t: T) -> T
+    where
+        T: Real,
+    {
+        let b = 0.00873;
+        let c = 0.241514;
+        let d = 0.598206;
+        if t < 0.181 {
+            (t - 0.125) / 5.6
+        } else {
+            10.0.powf((t - d) / c) - b
+        }
+    }
+
+    #[inline]
+    pub fn panasonic_vlog<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: panasonic_vlog_t(x.r),
+            g: panasonic_vlog_t(x.g),
+            b: panasonic_vlog_t(x.b),
+        }
+    }
+
+    /// Inverse of the Canon Log 2 encoding
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn canon_clog2_t<T>(t: T) -> T
+    where
+        T: Real,
+    {
+        let x = if t < 0.092864125 {
+            -(10.0.powf((0.092864125 - t) / 0.24136077) - 1.0) / 87.09937546
+        } else {
+            (10.0.powf((t - 0.092864125) / 0.24136077) - 1.0) / 87.09937546
+        };
+        x * 0.9
+    }
+
+    #[inline]
+    pub fn canon_clog2<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: canon_clog2_t(x.r),
+            g: canon_clog2_t(x.g),
+            b: canon_clog2_t(x.b),
+        }
+    }
+
+    /// Inverse of the Canon Log 3 encoding
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn canon_clog3_t<T>(t: T) -> T
+    where
+        T: Real,
+    {
+        let x = if t < 1.9754798 * -0.014 + 0.12512219 {
+            -(10.0.powf((0.12783901 - t) / 0.36726845) - 1.0) / 14.98325
+        } else if t <= 1.9754798 * 0.014 + 0.12512219 {
+            (t - 0.12512219) / 1.9754798
+        } else {
+            (10.0.powf((t - 0.12240537) / 0.36726845) - 1.0) / 14.98325
+        };
+        x * 0.9
+    }
+
+    #[inline]
+    pub fn canon_clog3<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: canon_clog3_t(x.r),
+            g: canon_clog3_t(x.g),
+            b: canon_clog3_t(x.b),
+        }
+    }
 
-    use crate::math::Real;
-    use crate::rgb::RGBf;
-    use numeric_literals::replace_float_literals;
+    /// Inverse of the Blackmagic Film Generation 5 encoding
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn blackmagic_film_gen5_t<T>(t: T) -> T
+    where
+        T: Real,
+    {
+        let a = 0.08692876065491224;
+        let b = 0.005494072432257808;
+        let c = 0.5300133392291939;
+        let d = 8.283605932402494;
+        let e = 0.09246575342465753;
+        if t < d * 0.005 + e {
+            (t - e) / d
+        } else {
+            ((t - c) / a).exp() - b
+        }
+    }
+
+    #[inline]
+    pub fn blackmagic_film_gen5<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: blackmagic_film_gen5_t(x.r),
+            g: blackmagic_film_gen5_t(x.g),
+            b: blackmagic_film_gen5_t(x.b),
+        }
+    }
 
+    /// Inverse of the ARRI LogC4 encoding
     #[inline]
     #[replace_float_literals(T::from(literal).unwrap())]
-    pub fn srgb_t<T>(f: T) -> T
+    pub fn alexa_logc_v4_t<T>(t: T) -> T
     where
         T: Real,
     {
-        if f <= 0.040449936 {
-            f / 12.92
+        let a = (2.0.powi(18) - 16.0) / 117.45;
+        let b = (1023.0 - 95.0) / 1023.0;
+        let c = 95.0 / 1023.0;
+        let s = (7.0 * 2.0.ln() * 2.0.powf(7.0 - 14.0 * c / b)) / (a * b);
+        let t_lin = (2.0.powf(14.0 * (-c / b) + 6.0) - 64.0) / a;
+        if t >= 0.0 {
+            (2.0.powf(14.0 * (t - c) / b + 6.0) - 64.0) / a
         } else {
-            ((f + 0.055) / 1.055).powf(2.4)
+            t * s + t_lin
         }
     }
 
     #[inline]
-    pub fn srgb<T>(x: RGBf<T>) -> RGBf<T>
+    pub fn alexa_logc_v4<T>(x: RGBf<T>) -> RGBf<T>
     where
         T: Real,
     {
         RGBf {
-            r: srgb_t(x.r),
-            g: srgb_t(x.g),
-            b: srgb_t(x.b),
+            r: alexa_logc_v4_t(x.r),
+            g: alexa_logc_v4_t(x.g),
+            b: alexa_logc_v4_t(x.b),
         }
     }
 
+    /// Inverse of the ProPhoto RGB (ROMM RGB) encoding
     #[inline]
     #[replace_float_literals(T::from(literal).unwrap())]
-    pub fn bt709_t<T>(f: T) -> T
+    pub fn prophoto_t<T>(t: T) -> T
     where
         T: Real,
     {
-        if f <= 0.018 * 4.5 {
-            f / 4.5
+        if t < 16.0 / 512.0 {
+            t / 16.0
         } else {
-            ((f + 0.099) / 1.099).powf(1.0 / 0.45)
+            t.powf(1.8)
         }
     }
 
     #[inline]
-    pub fn bt709<T>(x: RGBf<T>) -> RGBf<T>
+    pub fn prophoto<T>(x: RGBf<T>) -> RGBf<T>
     where
         T: Real,
     {
         RGBf {
-            r: bt709_t(x.r),
-            g: bt709_t(x.g),
-            b: bt709_t(x.b),
+            r: prophoto_t(x.r),
+            g: prophoto_t(x.g),
+            b: prophoto_t(x.b),
         }
     }
 
+    /// Inverse of the CIE L* encoding
     #[inline]
     #[replace_float_literals(T::from(literal).unwrap())]
-    pub fn bt2020_t<T>(f: T) -> T
+    pub fn lstar_t<T>(t: T) -> T
     where
         T: Real,
     {
-        let alpha = 1.099;
-        let beta = 0.018;
-        if f < beta * 4.5 {
-            f / 4.5
+        if t <= 0.08 {
+            t * 2700.0 / 24389.0
         } else {
-            ((f + (alpha - 1.0)) / alpha).powf(1.0 / 0.45)
+            ((t + 0.16) / 1.16).powi(3)
         }
     }
 
     #[inline]
-    pub fn bt2020<T>(x: RGBf<T>) -> RGBf<T>
+    pub fn lstar<T>(x: RGBf<T>) -> RGBf<T>
     where
         T: Real,
     {
         RGBf {
-            r: bt2020_t(x.r),
-            g: bt2020_t(x.g),
-            b: bt2020_t(x.b),
+            r: lstar_t(x.r),
+            g: lstar_t(x.g),
+            b: lstar_t(x.b),
         }
     }
 
+    /// Inverse of the ACEScc log encoding
     #[inline]
-    pub fn linear_t<T>(x: T) -> T
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn acescc_t<T>(t: T) -> T
     where
         T: Real,
     {
-        x
+        if t < (9.72 - 15.0) / 17.52 {
+            (2.0.powf(t * 17.52 - 9.72) - 2.0.powi(-16)) * 2.0
+        } else if t < (65504.0.log2() + 9.72) / 17.52 {
+            2.0.powf(t * 17.52 - 9.72)
+        } else {
+            65504.0
+        }
     }
 
     #[inline]
-    pub fn linear<T>(x: RGBf<T>) -> RGBf<T>
+    pub fn acescc<T>(x: RGBf<T>) -> RGBf<T>
     where
         T: Real,
     {
         RGBf {
-            r: linear_t(x.r),
-            g: linear_t(x.g),
-            b: linear_t(x.b),
+            r: acescc_t(x.r),
+            g: acescc_t(x.g),
+            b: acescc_t(x.b),
         }
     }
 
+    /// Inverse of the ACEScct log encoding
     #[inline]
     #[replace_float_literals(T::from(literal).unwrap())]
-    pub fn alexa_logc_v3_t<T>(t: T) -> T
+    pub fn acescct_t<T>(t: T) -> T
     where
         T: Real,
     {
-        // using parameters for EI 800
-        let a = 5.555556;
-        let b = 0.052272;
-        let c = 0.247190;
-        let d = 0.385537;
-        let e = 5.367655;
-        let f = 0.092809;
-        let ecf = 0.149658;
-        if t > ecf {
-            (10.0.powf((t - d) / c) - b) / a
+        let y_brk = 0.155251141552511;
+        let a = 10.5402377416545;
+        let b = 0.0729055341958355;
+        if t <= y_brk {
+            (t - b) / a
+        } else if t < (65504.0.log2() + 9.72) / 17.52 {
+            2.0.powf(t * 17.52 - 9.72)
         } else {
-            (t - f) / e
+            65504.0
+        }
+    }
+
+    #[inline]
+    pub fn acescct<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: acescct_t(x.r),
+            g: acescct_t(x.g),
+            b: acescct_t(x.b),
+        }
+    }
+
+    /// Inverse of the ACESproxy 10-bit log encoding
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn acesproxy_t<T>(t: T) -> T
+    where
+        T: Real,
+    {
+        let steps_per_stop = 50.0;
+        let mid_cv_offset = 425.0;
+        let mid_log_offset = 2.5;
+        2.0.powf((t * 1023.0 - mid_cv_offset) / steps_per_stop - mid_log_offset)
+    }
+
+    #[inline]
+    pub fn acesproxy<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: acesproxy_t(x.r),
+            g: acesproxy_t(x.g),
+            b: acesproxy_t(x.b),
+        }
+    }
+
+    /// SMPTE ST 2084 (PQ) EOTF. Returns display luminance normalised so that
+    /// 1.0 is 10000 cd/m².
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn pq_t<T>(t: T) -> T
+    where
+        T: Real,
+    {
+        let m1 = 2610.0 / 16384.0;
+        let m2 = 2523.0 / 4096.0 * 128.0;
+        let c1 = 3424.0 / 4096.0;
+        let c2 = 2413.0 / 4096.0 * 32.0;
+        let c3 = 2392.0 / 4096.0 * 32.0;
+
+        let e = t.max(0.0).powf(1.0 / m2);
+        ((e - c1).max(0.0) / (c2 - c3 * e)).powf(1.0 / m1)
+    }
+
+    #[inline]
+    pub fn pq<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: pq_t(x.r),
+            g: pq_t(x.g),
+            b: pq_t(x.b),
+        }
+    }
+
+    /// ARIB STD-B67 (HLG) inverse OETF, returning normalised scene-linear
+    /// light.
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn hlg_t<T>(t: T) -> T
+    where
+        T: Real,
+    {
+        let a = 0.17883277;
+        let b = 1.0 - 4.0 * a;
+        let c = 0.5 - a * (4.0 * a).ln();
+        if t <= 0.5 {
+            t * t / 3.0
+        } else {
+            (((t - c) / a).exp() + b) / 12.0
+        }
+    }
+
+    #[inline]
+    pub fn hlg<T>(x: RGBf<T>) -> RGBf<T>
+    where
+        T: Real,
+    {
+        RGBf {
+            r: hlg_t(x.r),
+            g: hlg_t(x.g),
+            b: hlg_t(x.b),
+        }
+    }
+
+    /// HLG EOTF: applies the inverse OETF, then the OOTF with the given
+    /// system gamma, returning normalised display-linear light. The black
+    /// level lift is taken to be zero.
+    #[inline]
+    pub fn hlg_eotf<T>(x: RGBf<T>, gamma: T) -> RGBf<T>
+    where
+        T: Real,
+    {
+        super::hlg_ootf(hlg(x), gamma)
+    }
+
+    /// ITU-R BT.1886 reference EOTF for a display with white luminance `l_w`
+    /// and black luminance `l_b`, returning luminance in the same units.
+    #[inline]
+    #[replace_float_literals(T::from(literal).unwrap())]
+    pub fn bt1886_t<T>(t: T, l_w: T, l_b: T) -> T
+    where
+        T: Real,
+    {
+        let w = l_w.powf(1.0 / 2.4);
+        let k = l_b.powf(1.0 / 2.4);
+        let a = (w - k).powf(2.4);
+        let b = k / (w - k);
+        a * (t + b).max(0.0).powf(2.4)
+    }
+}
+/// HLG system gamma for a display of nominal peak luminance `l_w` in cd/m²,
+/// as given in ITU-R BT.2100. This is 1.2 for a 1000 cd/m² display.
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn hlg_system_gamma<T>(l_w: T) -> T
+where
+    T: Real,
+{
+    1.2 + 0.42 * (l_w / 1000.0).log10()
+}
+
+/// HLG OOTF, mapping normalised scene-linear ITU-R BT.2100 RGB to normalised
+/// display-linear RGB with the given system gamma.
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn hlg_ootf<T>(x: RGBf<T>, gamma: T) -> RGBf<T>
+where
+    T: Real,
+{
+    let y_s = 0.2627 * x.r + 0.6780 * x.g + 0.0593 * x.b;
+    if y_s <= 0.0 {
+        return RGBf::from_scalar(0.0);
+    }
+    x * y_s.powf(gamma - 1.0)
+}
+
+/// Inverse of [hlg_ootf].
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn hlg_inverse_ootf<T>(x: RGBf<T>, gamma: T) -> RGBf<T>
+where
+    T: Real,
+{
+    let y_d = 0.2627 * x.r + 0.6780 * x.g + 0.0593 * x.b;
+    if y_d <= 0.0 {
+        return RGBf::from_scalar(0.0);
+    }
+    x * y_d.powf((1.0 - gamma) / gamma)
+}
+
+type CustomFn<T> = Arc<dyn Fn(RGBf<T>) -> RGBf<T> + Sync + Send>;
+
+/// A boxed OETF or EOTF, as taken by the old closure-based constructors.
+#[deprecated(note = "describe transfer functions with TransferCurve")]
+pub type TransferFunction<T> = Box<dyn Fn(RGBf<T>) -> RGBf<T> + Sync + Send>;
+
+/// Description of the transfer function pair used by an RGB color space.
+///
+/// Each variant knows how to encode (apply the OETF) and decode (apply the
+/// EOTF), and two curves can be compared, cloned and printed. Curves that are
+/// not covered by a dedicated variant can be described with
+/// [TransferCurve::Log] or [TransferCurve::IccParametric], or supplied as a
+/// pair of closures with [TransferCurve::custom].
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransferCurve<T>
+where
+    T: Real,
+{
+    /// No transfer function: values are stored linearly.
+    Linear,
+    /// The piecewise sRGB curve from IEC 61966-2-1.
+    Srgb,
+    /// A pure power law, encoding with `x^(1/γ)` and decoding with `x^γ`.
+    Gamma(T),
+    /// The ITU-R BT.709 OETF and its inverse.
+    Bt709,
+    /// The ITU-R BT.2020 OETF and its inverse.
+    Bt2020,
+    /// The ITU-R BT.1886 reference display EOTF for a display with white
+    /// luminance `l_w` and black luminance `l_b`. Decoded values are in the
+    /// same units as `l_w` and `l_b`.
+    Bt1886 { l_w: T, l_b: T },
+    /// An ICC parametric curve.
+    IccParametric(IccParametricCurve<T>),
+    /// A generic log curve.
+    Log(LogParameters<T>),
+    /// ROMM RGB (ProPhoto) curve.
+    ProPhoto,
+    /// The CIE L* curve, as used by eciRGB v2.
+    LStar,
+    /// ARRI LogC v3 (EI 800).
+    AlexaLogCV3,
+    /// ARRI LogC4.
+    AlexaLogCV4,
+    /// RED Log3G10.
+    RedLog3G10,
+    /// RED REDLogFilm.
+    RedLogFilm,
+    /// Sony S-Log3.
+    SonySLog3,
+    /// Panasonic V-Log.
+    PanasonicVLog,
+    /// Canon Log 2.
+    CanonCLog2,
+    /// Canon Log 3.
+    CanonCLog3,
+    /// Blackmagic Film Generation 5.
+    BlackmagicFilmGen5,
+    /// ACEScc.
+    AcesCc,
+    /// ACEScct.
+    AcesCct,
+    /// ACESproxy (10-bit).
+    AcesProxy,
+    /// SMPTE ST 2084 perceptual quantizer, normalised so that 1.0 is
+    /// 10000 cd/m².
+    Pq,
+    /// ITU-R BT.2100 hybrid log-gamma OETF and its inverse.
+    Hlg,
+    /// A user-supplied pair of functions.
+    Custom(CustomTransferCurve<T>),
+}
+
+impl<T> TransferCurve<T>
+where
+    T: Real,
+{
+    /// Describe a custom transfer function from a pair of closures. `name`
+    /// identifies the curve when it is printed.
+    ///
+    /// ```
+    /// use colorspace::*;
+    /// use colorspace::color_space_rgb::TransferCurve;
+    /// let curve = TransferCurve::custom(
+    ///     "cineon-ish",
+    ///     |c: RGBf64| c.powf(1.0 / 1.7),
+    ///     |c: RGBf64| c.powf(1.7),
+    /// );
+    /// assert_eq!(curve, curve.clone());
+    /// ```
+    pub fn custom<E, D>(name: &str, oetf: E, eotf: D) -> TransferCurve<T>
+    where
+        E: Fn(RGBf<T>) -> RGBf<T> + Sync + Send + 'static,
+        D: Fn(RGBf<T>) -> RGBf<T> + Sync + Send + 'static,
+    {
+        TransferCurve::Custom(CustomTransferCurve {
+            name: name.to_string(),
+            oetf: Arc::new(oetf),
+            eotf: Arc::new(eotf),
+        })
+    }
+
+    /// Wrap a pair of boxed closures from the old constructors in an
+    /// unnamed [TransferCurve::Custom]
+    #[allow(deprecated)]
+    fn from_boxed(
+        oetf: TransferFunction<T>,
+        eotf: TransferFunction<T>,
+    ) -> TransferCurve<T> {
+        TransferCurve::Custom(CustomTransferCurve {
+            name: "custom".to_string(),
+            oetf: Arc::from(oetf),
+            eotf: Arc::from(eotf),
+        })
+    }
+
+    /// Convert a linear color to its encoded form by applying the OETF.
+    pub fn encode(&self, x: RGBf<T>) -> RGBf<T> {
+        match self {
+            TransferCurve::Linear => x,
+            TransferCurve::Srgb => encode::srgb(x),
+            TransferCurve::Gamma(g) => x.powf(T::one() / *g),
+            TransferCurve::Bt709 => encode::bt709(x),
+            TransferCurve::Bt2020 => encode::bt2020(x),
+            TransferCurve::Bt1886 { l_w, l_b } => {
+                map_rgb(x, |t| encode::bt1886_t(t, *l_w, *l_b))
+            }
+            TransferCurve::IccParametric(p) => map_rgb(x, |t| p.encode(t)),
+            TransferCurve::Log(p) => map_rgb(x, |t| p.encode(t)),
+            TransferCurve::ProPhoto => encode::prophoto(x),
+            TransferCurve::LStar => encode::lstar(x),
+            TransferCurve::AlexaLogCV3 => encode::alexa_logc_v3(x),
+            TransferCurve::AlexaLogCV4 => encode::alexa_logc_v4(x),
+            TransferCurve::RedLog3G10 => encode::red_log3g10(x),
+            TransferCurve::RedLogFilm => encode::red_logfilm(x),
+            TransferCurve::SonySLog3 => encode::sony_slog3(x),
+            TransferCurve::PanasonicVLog => encode::panasonic_vlog(x),
+            TransferCurve::CanonCLog2 => encode::canon_clog2(x),
+            TransferCurve::CanonCLog3 => encode::canon_clog3(x),
+            TransferCurve::BlackmagicFilmGen5 => {
+                encode::blackmagic_film_gen5(x)
+            }
+            TransferCurve::AcesCc => encode::acescc(x),
+            TransferCurve::AcesCct => encode::acescct(x),
+            TransferCurve::AcesProxy => encode::acesproxy(x),
+            TransferCurve::Pq => encode::pq(x),
+            TransferCurve::Hlg => encode::hlg(x),
+            TransferCurve::Custom(c) => (c.oetf)(x),
+        }
+    }
+
+    /// Convert an encoded color back to linear by applying the EOTF.
+    pub fn decode(&self, x: RGBf<T>) -> RGBf<T> {
+        match self {
+            TransferCurve::Linear => x,
+            TransferCurve::Srgb => decode::srgb(x),
+            TransferCurve::Gamma(g) => x.powf(*g),
+            TransferCurve::Bt709 => decode::bt709(x),
+            TransferCurve::Bt2020 => decode::bt2020(x),
+            TransferCurve::Bt1886 { l_w, l_b } => {
+                map_rgb(x, |t| decode::bt1886_t(t, *l_w, *l_b))
+            }
+            TransferCurve::IccParametric(p) => map_rgb(x, |t| p.decode(t)),
+            TransferCurve::Log(p) => map_rgb(x, |t| p.decode(t)),
+            TransferCurve::ProPhoto => decode::prophoto(x),
+            TransferCurve::LStar => decode::lstar(x),
+            TransferCurve::AlexaLogCV3 => decode::alexa_logc_v3(x),
+            TransferCurve::AlexaLogCV4 => decode::alexa_logc_v4(x),
+            TransferCurve::RedLog3G10 => decode::red_log3g10(x),
+            TransferCurve::RedLogFilm => decode::red_logfilm(x),
+            TransferCurve::SonySLog3 => decode::sony_slog3(x),
+            TransferCurve::PanasonicVLog => decode::panasonic_vlog(x),
+            TransferCurve::CanonCLog2 => decode::canon_clog2(x),
+            TransferCurve::CanonCLog3 => decode::canon_clog3(x),
+            TransferCurve::BlackmagicFilmGen5 => {
+                decode::blackmagic_film_gen5(x)
+            }
+            TransferCurve::AcesCc => decode::acescc(x),
+            TransferCurve::AcesCct => decode::acescct(x),
+            TransferCurve::AcesProxy => decode::acesproxy(x),
+            TransferCurve::Pq => decode::pq(x),
+            TransferCurve::Hlg => decode::hlg(x),
+            TransferCurve::Custom(c) => (c.eotf)(x),
+        }
+    }
+}
+
+fn map_rgb<T, F>(x: RGBf<T>, f: F) -> RGBf<T>
+where
+    T: Real,
+    F: Fn(T) -> T,
+{
+    RGBf {
+        r: f(x.r),
+        g: f(x.g),
+        b: f(x.b),
+    }
+}
+
+/// A pair of user-supplied transfer functions. Two custom curves compare equal
+/// only if they have the same name and share the same closures, i.e. one is a
+/// clone of the other.
+#[derive(Clone)]
+pub struct CustomTransferCurve<T>
+where
+    T: Real,
+{
+    pub name: String,
+    oetf: CustomFn<T>,
+    eotf: CustomFn<T>,
+}
+
+impl<T> std::fmt::Debug for CustomTransferCurve<T>
+where
+    T: Real,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CustomTransferCurve")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+impl<T> PartialEq for CustomTransferCurve<T>
+where
+    T: Real,
+{
+    fn eq(&self, other: &CustomTransferCurve<T>) -> bool {
+        // compare data pointers only, vtables may be duplicated
+        self.name == other.name
+            && Arc::as_ptr(&self.oetf) as *const ()
+                == Arc::as_ptr(&other.oetf) as *const ()
+            && Arc::as_ptr(&self.eotf) as *const ()
+                == Arc::as_ptr(&other.eotf) as *const ()
+    }
+}
+
+/// Parameters of an ICC parametric curve (ICC.1:2010, `parametricCurveType`).
+///
+/// The curve maps an encoded value `x` to a linear value `y` and is stored in
+/// the general form of function type 4:
+///
+/// ```text
+/// y = (a·x + b)^g + e   for x >= d
+/// y = c·x + f           for x < d
+/// ```
+///
+/// The simpler function types are built with the `type_0` to `type_3`
+/// constructors.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IccParametricCurve<T>
+where
+    T: Real,
+{
+    pub g: T,
+    pub a: T,
+    pub b: T,
+    pub c: T,
+    pub d: T,
+    pub e: T,
+    pub f: T,
+}
+
+impl<T> IccParametricCurve<T>
+where
+    T: Real,
+{
+    /// Function type 0: `y = x^g`
+    pub fn type_0(g: T) -> IccParametricCurve<T> {
+        IccParametricCurve::type_4(
+            g,
+            T::one(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+        )
+    }
+
+    /// Function type 1: `y = (a·x + b)^g` for `x >= -b/a`, otherwise 0
+    pub fn type_1(g: T, a: T, b: T) -> IccParametricCurve<T> {
+        IccParametricCurve::type_4(
+            g,
+            a,
+            b,
+            T::zero(),
+            -b / a,
+            T::zero(),
+            T::zero(),
+        )
+    }
+
+    /// Function type 2: `y = (a·x + b)^g + c` for `x >= -b/a`, otherwise `c`
+    pub fn type_2(g: T, a: T, b: T, c: T) -> IccParametricCurve<T> {
+        IccParametricCurve::type_4(g, a, b, T::zero(), -b / a, c, c)
+    }
+
+    /// Function type 3: `y = (a·x + b)^g` for `x >= d`, otherwise `c·x`
+    pub fn type_3(g: T, a: T, b: T, c: T, d: T) -> IccParametricCurve<T> {
+        IccParametricCurve::type_4(g, a, b, c, d, T::zero(), T::zero())
+    }
+
+    /// Function type 4: `y = (a·x + b)^g + e` for `x >= d`, otherwise
+    /// `c·x + f`
+    #[allow(clippy::too_many_arguments, clippy::many_single_char_names)]
+    pub fn type_4(
+        g: T,
+        a: T,
+        b: T,
+        c: T,
+        d: T,
+        e: T,
+        f: T,
+    ) -> IccParametricCurve<T> {
+        IccParametricCurve {
+            g,
+            a,
+            b,
+            c,
+            d,
+            e,
+            f,
+        }
+    }
+
+    /// Evaluate the curve, converting an encoded value to linear.
+    pub fn decode(&self, x: T) -> T {
+        if x >= self.d {
+            (self.a * x + self.b).powf(self.g) + self.e
+        } else {
+            self.c * x + self.f
+        }
+    }
+
+    /// Evaluate the inverse of the curve, converting a linear value to its
+    /// encoded form. Where the linear segment is flat the break point `d` is
+    /// returned.
+    pub fn encode(&self, y: T) -> T {
+        let y_d = (self.a * self.d + self.b).powf(self.g) + self.e;
+        if y >= y_d {
+            ((y - self.e).powf(T::one() / self.g) - self.b) / self.a
+        } else if self.c != T::zero() {
+            (y - self.f) / self.c
+        } else {
+            self.d
+        }
+    }
+}
+
+/// Parameters of a generic camera log curve, in the form used by
+/// OpenColorIO's `LogCameraTransform`. A linear value `x` is encoded as
+///
+/// ```text
+/// y = log_side_slope · log_base(lin_side_slope · x + lin_side_offset)
+///     + log_side_offset
+/// ```
+///
+/// If `lin_side_break` is given, values below it are encoded with a straight
+/// line matching the value and slope of the log segment at the break.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LogParameters<T>
+where
+    T: Real,
+{
+    pub base: T,
+    pub log_side_slope: T,
+    pub log_side_offset: T,
+    pub lin_side_slope: T,
+    pub lin_side_offset: T,
+    pub lin_side_break: Option<T>,
+}
+
+impl<T> LogParameters<T>
+where
+    T: Real,
+{
+    fn log_segment(&self, x: T) -> T {
+        self.log_side_slope
+            * (self.lin_side_slope * x + self.lin_side_offset).log(self.base)
+            + self.log_side_offset
+    }
+
+    // slope and offset of the linear segment
+    fn linear_segment(&self, brk: T) -> (T, T) {
+        let slope = self.log_side_slope * self.lin_side_slope
+            / ((self.lin_side_slope * brk + self.lin_side_offset)
+                * self.base.ln());
+        (slope, self.log_segment(brk) - slope * brk)
+    }
+
+    /// Encode the linear value `x`.
+    pub fn encode(&self, x: T) -> T {
+        match self.lin_side_break {
+            Some(brk) if x < brk => {
+                let (slope, offset) = self.linear_segment(brk);
+                slope * x + offset
+            }
+            _ => self.log_segment(x),
         }
     }
 
-    #[inline]
-    pub fn alexa_logc_v3<T>(x: RGBf<T>) -> RGBf<T>
-    where
-        T: Real,
-    {
-        RGBf {
-            r: alexa_logc_v3_t(x.r),
-            g: alexa_logc_v3_t(x.g),
-            b: alexa_logc_v3_t(x.b),
+    /// Decode the log value `y` to linear.
+    pub fn decode(&self, y: T) -> T {
+        if let Some(brk) = self.lin_side_break {
+            let (slope, offset) = self.linear_segment(brk);
+            if y < slope * brk + offset {
+                return (y - offset) / slope;
+            }
         }
+        (self
+            .base
+            .powf((y - self.log_side_offset) / self.log_side_slope)
+            - self.lin_side_offset)
+            / self.lin_side_slope
     }
 }
-pub type TransferFunction<T> = Box<dyn Fn(RGBf<T>) -> RGBf<T> + Sync + Send>;
 
 /// Defines a tristimulus RGB color space as a collection of primaries, a
 /// whitepoint and OETF.
+#[derive(Clone, Debug, PartialEq)]
 pub struct ColorSpaceRGB<T>
 where
     T: Real,
@@ -298,8 +1665,7 @@ where
     pub green: XYY<T>,
     pub blue: XYY<T>,
     pub white: XYY<T>,
-    pub oetf: TransferFunction<T>,
-    pub eotf: TransferFunction<T>,
+    pub transfer: TransferCurve<T>,
 }
 
 /// Create a new color space using the supplied primaries and transfer functions
@@ -315,8 +1681,7 @@ where
 ///         y: 0.351,
 ///         Y: 1.0,
 ///     },
-///     Box::new(|c: RGBf64| c.powf(1.0 / 2.6)),
-///     Box::new(|c: RGBf64| c.powf(2.6)),
+///     TransferCurve::Gamma(2.6),
 /// );
 /// ```
 impl<T> ColorSpaceRGB<T>
@@ -328,8 +1693,7 @@ where
         green: XYY<T>,
         blue: XYY<T>,
         white: XYY<T>,
-        oetf: TransferFunction<T>,
-        eotf: TransferFunction<T>,
+        transfer: TransferCurve<T>,
     ) -> ColorSpaceRGB<T> {
         let xf_xyz_to_rgb =
             build_xyz_to_rgb_matrix(&red, &green, &blue, &white);
@@ -342,8 +1706,7 @@ where
             green,
             blue,
             white,
-            oetf,
-            eotf,
+            transfer,
         }
     }
 
@@ -371,8 +1734,7 @@ where
     ///     M3f64::new([0.4124, 0.3576, 0.1805,
     ///                    0.2126, 0.7152, 0.0722,
     ///                    0.0193, 0.1192, 0.9505]),
-    ///     Box::new(encode::srgb),
-    ///     Box::new(decode::srgb),
+    ///     TransferCurve::Srgb,
     /// );
     /// ```
     #[allow(clippy::too_many_arguments)]
@@ -383,8 +1745,7 @@ where
         white: XYY<T>,
         xf_xyz_to_rgb: Matrix33<T>,
         xf_rgb_to_xyz: Matrix33<T>,
-        oetf: TransferFunction<T>,
-        eotf: TransferFunction<T>,
+        transfer: TransferCurve<T>,
     ) -> ColorSpaceRGB<T> {
         ColorSpaceRGB {
             xf_xyz_to_rgb,
@@ -393,18 +1754,83 @@ where
             green,
             blue,
             white,
-            oetf,
-            eotf,
+            transfer,
         }
     }
 
+    /// Create a new color space from a pair of boxed transfer functions, as
+    /// [ColorSpaceRGB::new] did before transfer functions were described by
+    /// [TransferCurve]. The pair is wrapped in a [TransferCurve::Custom].
+    #[deprecated(note = "use ColorSpaceRGB::new with a TransferCurve")]
+    #[allow(deprecated)]
+    pub fn from_transfer_functions(
+        red: XYY<T>,
+        green: XYY<T>,
+        blue: XYY<T>,
+        white: XYY<T>,
+        oetf: TransferFunction<T>,
+        eotf: TransferFunction<T>,
+    ) -> ColorSpaceRGB<T> {
+        ColorSpaceRGB::new(
+            red,
+            green,
+            blue,
+            white,
+            TransferCurve::from_boxed(oetf, eotf),
+        )
+    }
+
+    /// Create a new color space from the supplied matrices and a pair of
+    /// boxed transfer functions, as [ColorSpaceRGB::new_with_specified_matrices]
+    /// did before transfer functions were described by [TransferCurve]. The
+    /// pair is wrapped in a [TransferCurve::Custom].
+    #[deprecated(
+        note = "use ColorSpaceRGB::new_with_specified_matrices with a \
+                TransferCurve"
+    )]
+    #[allow(deprecated, clippy::too_many_arguments)]
+    pub fn from_transfer_functions_with_specified_matrices(
+        red: XYY<T>,
+        green: XYY<T>,
+        blue: XYY<T>,
+        white: XYY<T>,
+        xf_xyz_to_rgb: Matrix33<T>,
+        xf_rgb_to_xyz: Matrix33<T>,
+        oetf: TransferFunction<T>,
+        eotf: TransferFunction<T>,
+    ) -> ColorSpaceRGB<T> {
+        ColorSpaceRGB::new_with_specified_matrices(
+            red,
+            green,
+            blue,
+            white,
+            xf_xyz_to_rgb,
+            xf_rgb_to_xyz,
+            TransferCurve::from_boxed(oetf, eotf),
+        )
+    }
+
+    /// Returns the opto-electrical transfer function of this color space,
+    /// which used to be the public `oetf` field. Equivalent to
+    /// [ColorSpaceRGB::encode].
+    pub fn oetf(&self) -> impl Fn(RGBf<T>) -> RGBf<T> + '_ {
+        move |c| self.transfer.encode(c)
+    }
+
+    /// Returns the electro-optical transfer function of this color space,
+    /// which used to be the public `eotf` field. Equivalent to
+    /// [ColorSpaceRGB::decode].
+    pub fn eotf(&self) -> impl Fn(RGBf<T>) -> RGBf<T> + '_ {
+        move |c| self.transfer.decode(c)
+    }
+
     /// Convert a scene-referred, linear color to a display-referred, possibly
     /// non-linear color using the opto-electrical transfer function.
     /// If the color space does not have an associated OETF then it simply
     /// returns `c` unaltered.
     #[inline(always)]
     pub fn encode(&self, c: RGBf<T>) -> RGBf<T> {
-        (self.oetf)(c)
+        self.transfer.encode(c)
     }
 
     /// Convert a display-referred, possibly non-linear color to a
@@ -413,7 +1839,102 @@ where
     /// returns `c` unaltered.
     #[inline(always)]
     pub fn decode(&self, c: RGBf<T>) -> RGBf<T> {
-        (self.eotf)(c)
+        self.transfer.decode(c)
+    }
+}
+
+impl From<ColorSpaceRGB<f64>> for ColorSpaceRGB<f32> {
+    fn from(c: ColorSpaceRGB<f64>) -> ColorSpaceRGB<f32> {
+        ColorSpaceRGB {
+            xf_xyz_to_rgb: c.xf_xyz_to_rgb.into(),
+            xf_rgb_to_xyz: c.xf_rgb_to_xyz.into(),
+            red: c.red.into(),
+            green: c.green.into(),
+            blue: c.blue.into(),
+            white: c.white.into(),
+            transfer: c.transfer.into(),
+        }
+    }
+}
+
+impl From<&ColorSpaceRGB<f64>> for ColorSpaceRGB<f32> {
+    fn from(c: &ColorSpaceRGB<f64>) -> ColorSpaceRGB<f32> {
+        c.clone().into()
+    }
+}
+
+/// Custom curves are wrapped so that they evaluate in 64-bit.
+impl From<TransferCurve<f64>> for TransferCurve<f32> {
+    fn from(t: TransferCurve<f64>) -> TransferCurve<f32> {
+        match t {
+            TransferCurve::Linear => TransferCurve::Linear,
+            TransferCurve::Srgb => TransferCurve::Srgb,
+            TransferCurve::Gamma(g) => TransferCurve::Gamma(g as f32),
+            TransferCurve::Bt709 => TransferCurve::Bt709,
+            TransferCurve::Bt2020 => TransferCurve::Bt2020,
+            TransferCurve::Bt1886 { l_w, l_b } => TransferCurve::Bt1886 {
+                l_w: l_w as f32,
+                l_b: l_b as f32,
+            },
+            TransferCurve::IccParametric(p) => {
+                TransferCurve::IccParametric(p.into())
+            }
+            TransferCurve::Log(p) => TransferCurve::Log(p.into()),
+            TransferCurve::ProPhoto => TransferCurve::ProPhoto,
+            TransferCurve::LStar => TransferCurve::LStar,
+            TransferCurve::AlexaLogCV3 => TransferCurve::AlexaLogCV3,
+            TransferCurve::AlexaLogCV4 => TransferCurve::AlexaLogCV4,
+            TransferCurve::RedLog3G10 => TransferCurve::RedLog3G10,
+            TransferCurve::RedLogFilm => TransferCurve::RedLogFilm,
+            TransferCurve::SonySLog3 => TransferCurve::SonySLog3,
+            TransferCurve::PanasonicVLog => TransferCurve::PanasonicVLog,
+            TransferCurve::CanonCLog2 => TransferCurve::CanonCLog2,
+            TransferCurve::CanonCLog3 => TransferCurve::CanonCLog3,
+            TransferCurve::BlackmagicFilmGen5 => {
+                TransferCurve::BlackmagicFilmGen5
+            }
+            TransferCurve::AcesCc => TransferCurve::AcesCc,
+            TransferCurve::AcesCct => TransferCurve::AcesCct,
+            TransferCurve::AcesProxy => TransferCurve::AcesProxy,
+            TransferCurve::Pq => TransferCurve::Pq,
+            TransferCurve::Hlg => TransferCurve::Hlg,
+            TransferCurve::Custom(c) => {
+                let oetf = c.oetf;
+                let eotf = c.eotf;
+                TransferCurve::custom(
+                    &c.name,
+                    move |x: RGBf<f32>| oetf(x.into()).into(),
+                    move |x: RGBf<f32>| eotf(x.into()).into(),
+                )
+            }
+        }
+    }
+}
+
+impl From<IccParametricCurve<f64>> for IccParametricCurve<f32> {
+    fn from(p: IccParametricCurve<f64>) -> IccParametricCurve<f32> {
+        IccParametricCurve {
+            g: p.g as f32,
+            a: p.a as f32,
+            b: p.b as f32,
+            c: p.c as f32,
+            d: p.d as f32,
+            e: p.e as f32,
+            f: p.f as f32,
+        }
+    }
+}
+
+impl From<LogParameters<f64>> for LogParameters<f32> {
+    fn from(p: LogParameters<f64>) -> LogParameters<f32> {
+        LogParameters {
+            base: p.base as f32,
+            log_side_slope: p.log_side_slope as f32,
+            log_side_offset: p.log_side_offset as f32,
+            lin_side_slope: p.lin_side_slope as f32,
+            lin_side_offset: p.lin_side_offset as f32,
+            lin_side_break: p.lin_side_break.map(|b| b as f32),
+        }
     }
 }
 
@@ -472,420 +1993,777 @@ where
     ])
 }
 
-pub mod model_f64 {
-    use super::*;
-
-    lazy_static! {
-
-        /// sRGB
-        /// Data taken https://en.wikipedia.org/wiki/SRGB
-        pub static ref SRGB: ColorSpaceRGB<f64> = {
-            ColorSpaceRGB::new_with_specified_matrices(
-                XYYf64 { x: 0.64, y: 0.33, Y: 1.0 },
-                XYYf64 { x: 0.30, y: 0.60, Y: 1.0 },
-                XYYf64 { x: 0.15, y: 0.06, Y: 1.0 },
-                XYYf64 {
-                    x: 0.3127,
-                    y: 0.3290,
-                    Y: 1.0,
-                },
-                M3f64::new([
-                    3.2406, -1.5372, -0.4986,
-                    -0.9689, 1.8758, 0.0415,
-                    0.0557, -0.2040, 1.0570
-                    ]),
-                M3f64::new([
-                    0.4124, 0.3576, 0.1805,
-                    0.2126, 0.7152, 0.0722,
-                    0.0193, 0.1192, 0.9505
-                    ]),
-                Box::new(encode::srgb),
-                Box::new(decode::srgb),
-            )
-        };
-
-        /// sRGB - derived matrices
-        /// Data taken https://en.wikipedia.org/wiki/SRGB
-        pub static ref SRGB_DRV: ColorSpaceRGB<f64> = {
-            ColorSpaceRGB::new(
-                XYYf64 { x: 0.64, y: 0.33, Y: 1.0 },
-                XYYf64 { x: 0.30, y: 0.60, Y: 1.0 },
-                XYYf64 { x: 0.15, y: 0.06, Y: 1.0 },
-                XYYf64 {
-                    x: 0.3127,
-                    y: 0.3290,
-                    Y: 1.0,
-                },
-                Box::new(encode::srgb),
-                Box::new(decode::srgb),
-            )
-        };
-
-        /// ITU-R Rec. BT.709
-        /// Data taken from https://en.wikipedia.org/wiki/Rec._709
-        pub static ref ITUR_BT709: ColorSpaceRGB<f64> = {
-            ColorSpaceRGB::new(
-                XYYf64 { x: 0.64, y: 0.33, Y: 1.0 },
-                XYYf64 { x: 0.30, y: 0.60, Y: 1.0 },
-                XYYf64 { x: 0.15, y: 0.06, Y: 1.0 },
-                XYYf64 {
-                    x: 0.3127,
-                    y: 0.3290,
-                    Y: 1.0,
-                },
-                Box::new(encode::bt709),
-                Box::new(decode::bt709),
-            )
-        };
-
-        /// ITU-R Rec. BT.2020
-        /// Data taken from https://en.wikipedia.org/wiki/Rec._2020
-        /// See also https://www.itu.int/rec/R-REC-BT.1886-0-201103-I
-        pub static ref ITUR_BT2020: ColorSpaceRGB<f64> = {
-            ColorSpaceRGB::new(
-                XYYf64 { x: 0.708, y: 0.292, Y: 1.0 },
-                XYYf64 { x: 0.17, y: 0.797, Y: 1.0 },
-                XYYf64 { x: 0.131, y: 0.046, Y: 1.0 },
-                XYYf64 {
-                    x: 0.3127,
-                    y: 0.3290,
-                    Y: 1.0,
-                },
-                Box::new(encode::bt2020),
-                Box::new(decode::bt2020),
-            )
-        };
-
-        /// DCI-P3
-        /// Data taken from https://en.wikipedia.org/wiki/DCI-P3
-        pub static ref DCI_P3: ColorSpaceRGB<f64> = {
-            ColorSpaceRGB::new(
-                XYYf64 { x: 0.680, y: 0.320, Y: 1.0 },
-                XYYf64 { x: 0.265, y: 0.690, Y: 1.0 },
-                XYYf64 { x: 0.150, y: 0.060 , Y: 1.0},
-                XYYf64 {
-                    x: 0.314,
-                    y: 0.351,
-                    Y: 1.0,
-                },
-                Box::new(|c: RGBf64| c.powf(1.0 / 2.6)),
-                Box::new(|c: RGBf64| c.powf(2.6)),
-            )
-        };
-
-        /// P3 D65
-        /// Data taken from https://en.wikipedia.org/wiki/DCI-P3
-        pub static ref DCI_P3_D65: ColorSpaceRGB<f64> = {
-            ColorSpaceRGB::new(
-                XYYf64 { x: 0.680, y: 0.320 , Y: 1.0},
-                XYYf64 { x: 0.265, y: 0.690 , Y: 1.0},
-                XYYf64 { x: 0.150, y: 0.060 , Y: 1.0},
-                XYYf64 {
-                    x: 0.3127,
-                    y: 0.3290,
-                    Y: 1.0,
-                },
-                Box::new(|c: RGBf64| c.powf(1.0 / 2.6)),
-                Box::new(|c: RGBf64| c.powf(2.6)),
-            )
-        };
-
-        /// ACES archival color space. AP0 primaries.
-        /// Data taken from https://en.wikipedia.org/wiki/Academy_Color_Encoding_System
-        pub static ref ACES: ColorSpaceRGB<f64> = {
-            ColorSpaceRGB::new_with_specified_matrices(
-                XYYf64 { x: 0.7347, y: 0.2653, Y: 1.0},
-                XYYf64 { x: 0.0000, y: 1.0000, Y: 1.0},
-                XYYf64 { x: 0.0001, y: -0.077, Y: 1.0},
-                XYYf64 {
-                    x: 0.32168,
-                    y: 0.33767,
-                    Y: 1.0,
-                },
-                M3f64::new([
-                    1.0498110175, 0.0000000000, -0.0000974845,
-                    -0.4959030231, 1.3733130458, 0.0982400361,
-                    0.0000000000, 0.0000000000, 0.9912520182,
-                ]),
-                M3f64::new([
-                    0.9525523959, 0.0000000000, 0.0000936786,
-                    0.3439664498, 0.7281660966, -0.0721325464,
-                    0.0000000000, 0.0000000000, 1.0088251844,
-                ]),
-                Box::new(encode::linear),
-                Box::new(decode::linear),
-            )
-        };
-
-        /// ACEScg color space. AP1 primaries.
-        /// Data taken from https://en.wikipedia.org/wiki/Academy_Color_Encoding_System
-        pub static ref ACES_CG: ColorSpaceRGB<f64> = {
-            ColorSpaceRGB::new(
-                XYYf64 { x: 0.713, y: 0.293, Y: 1.0},
-                XYYf64 { x: 0.165, y: 0.830, Y: 1.0},
-                XYYf64 { x: 0.128, y: 0.044, Y: 1.0},
-                XYYf64 {
-                    x: 0.32168,
-                    y: 0.33767,
-                    Y: 1.0,
-                },
-                Box::new(encode::linear),
-                Box::new(decode::linear),
-            )
-        };
-
-        /// Adobe RGB (1998)
-        /// Data taken from
-        /// https://www.adobe.com/digitalimag/pdfs/AdobeRGB1998.pdf
-        pub static ref ADOBE_RGB_1998: ColorSpaceRGB<f64> = {
-            ColorSpaceRGB::new_with_specified_matrices(
-                XYYf64 { x: 0.6400, y: 0.3300, Y: 1.0},
-                XYYf64 { x: 0.2100, y: 0.7100, Y: 1.0},
-                XYYf64 { x: 0.1500, y: 0.0600, Y: 1.0},
-                XYYf64 {
-                    x: 0.3127,
-                    y: 0.3290,
-                    Y: 1.0,
-                },
-                M3f64::new([
-                    2.04159, -0.56501, -0.34473,
-                    -0.96924, 1.87597, 0.04156,
-                    0.01344, -0.11836, 1.01517,
-                ]),
-                M3f64::new([
-                    0.57667, 0.18556, 0.18823,
-                    0.29734, 0.62736, 0.07529,
-                    0.02703, 0.07069, 0.99134,
-                ]),
-                Box::new(|c: RGBf64| c.powf(1.0 / 2.19921875)),
-                Box::new(|c: RGBf64| c.powf(2.19921875)),
-            )
-        };
+/// Defines every RGB color space model once and generates both a module of
+/// 64-bit statics and a module of 32-bit statics converted from them, so the
+/// two precisions can't drift apart.
+macro_rules! color_space_models {
+    (
+        $(#[$m64:meta])* $vis64:vis mod $f64:ident;
+        $(#[$m32:meta])* $vis32:vis mod $f32:ident;
+        $($(#[$attr:meta])* $name:ident = $def:expr;)*
+    ) => {
+        $(#[$m64])*
+        $vis64 mod $f64 {
+            use super::*;
+
+            lazy_static! {
+                $(
+                    $(#[$attr])*
+                    pub static ref $name: ColorSpaceRGB<f64> = $def;
+                )*
+            }
+        }
 
-        /// ARRI Alexa Wide Gamut.
-        /// Data taken from "Alexa LogC Curve in VFX"
-        pub static ref ALEXA_WIDE_GAMUT: ColorSpaceRGB<f64> = {
-            ColorSpaceRGB::new_with_specified_matrices(
-                XYYf64 { x: 0.6840, y: 0.3130, Y: 1.0},
-                XYYf64 { x: 0.2210, y: 0.8480, Y: 1.0},
-                XYYf64 { x: 0.0861, y: -0.102, Y: 1.0},
-                XYYf64 {
-                    x: 0.3127,
-                    y: 0.3290,
-                    Y: 1.0,
-                },
-                M3f64::new([
-                    1.789066, -0.482534, -0.200076,
-                    -0.639849, 1.396400, 0.194432,
-                    -0.041532, 0.082335, 0.878868,
-                ]),
-                M3f64::new([
-                    0.638008, 0.214704, 0.097744,
-                    0.291954, 0.823841, -0.115795,
-                    0.002798, -0.067034, 1.153294,
-                ]),
-                Box::new(encode::alexa_logc_v3),
-                Box::new(decode::alexa_logc_v3),
-            )
-        };
-    }
+        $(#[$m32])*
+        $vis32 mod $f32 {
+            use super::*;
+
+            lazy_static! {
+                $(
+                    #[doc = concat!(
+                        "32-bit version of [",
+                        stringify!($f64),
+                        "::",
+                        stringify!($name),
+                        "](super::",
+                        stringify!($f64),
+                        "::",
+                        stringify!($name),
+                        ")"
+                    )]
+                    pub static ref $name: ColorSpaceRGB<f32> =
+                        ColorSpaceRGB::from(&*super::$f64::$name);
+                )*
+            }
+        }
+    };
 }
-
-pub mod model_f32 {
-    use super::*;
-
-    lazy_static! {
-
-        /// sRGB
-        /// Data taken https://en.wikipedia.org/wiki/SRGB
-        pub static ref SRGB: ColorSpaceRGB<f32> = {
-            ColorSpaceRGB::new_with_specified_matrices(
-                XYYf32 { x: 0.64, y: 0.33, Y: 1.0 },
-                XYYf32 { x: 0.30, y: 0.60, Y: 1.0 },
-                XYYf32 { x: 0.15, y: 0.06, Y: 1.0 },
-                XYYf32 {
-                    x: 0.3127,
-                    y: 0.3290,
-                    Y: 1.0,
-                },
-                M3f32::new([3.2406, -1.5372, -0.4986,
+pub(crate) use color_space_models;
+
+color_space_models! {
+    /// 64-bit definitions of the built-in RGB color spaces
+    pub mod model_f64;
+    /// 32-bit versions of every model in [model_f64], converted from the
+    /// 64-bit definitions
+    pub mod model_f32;
+
+    /// sRGB
+    /// Data taken https://en.wikipedia.org/wiki/SRGB
+    SRGB = {
+        ColorSpaceRGB::new_with_specified_matrices(
+            XYYf64 { x: 0.64, y: 0.33, Y: 1.0 },
+            XYYf64 { x: 0.30, y: 0.60, Y: 1.0 },
+            XYYf64 { x: 0.15, y: 0.06, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            M3f64::new([
+                3.2406, -1.5372, -0.4986,
                 -0.9689, 1.8758, 0.0415,
-                0.0557, -0.2040, 1.0570]),
-                M3f32::new([0.4124, 0.3576, 0.1805,
-                0.2126, 0.7152, 0.0722,
-                0.0193, 0.1192, 0.9505]),
-                Box::new(encode::srgb),
-                Box::new(decode::srgb),
-            )
-        };
-
-        /// ITU-R Rec. BT.709
-        /// Data taken from https://en.wikipedia.org/wiki/Rec._709
-        pub static ref ITUR_BT709: ColorSpaceRGB<f32> = {
-            ColorSpaceRGB::new(
-                XYYf32 { x: 0.64, y: 0.33, Y: 1.0 },
-                XYYf32 { x: 0.30, y: 0.60, Y: 1.0 },
-                XYYf32 { x: 0.15, y: 0.06, Y: 1.0 },
-                XYYf32 {
-                    x: 0.3127,
-                    y: 0.3290,
-                    Y: 1.0,
-                },
-                Box::new(encode::bt709),
-                Box::new(decode::bt709),
-            )
-        };
-
-        /// ITU-R Rec. BT.2020
-        /// Data taken from https://en.wikipedia.org/wiki/Rec._2020
-        pub static ref ITUR_BT2020: ColorSpaceRGB<f32> = {
-            ColorSpaceRGB::new(
-                XYYf32 { x: 0.708, y: 0.292, Y: 1.0 },
-                XYYf32 { x: 0.17, y: 0.797, Y: 1.0 },
-                XYYf32 { x: 0.131, y: 0.046, Y: 1.0 },
-                XYYf32 {
-                    x: 0.3127,
-                    y: 0.3290,
-                    Y: 1.0,
-                },
-                Box::new(encode::bt2020),
-                Box::new(decode::bt2020),
-            )
-        };
-
-        /// DCI-P3
-        /// Data taken from https://en.wikipedia.org/wiki/DCI-P3
-        pub static ref DCI_P3: ColorSpaceRGB<f32> = {
-            ColorSpaceRGB::new(
-                XYYf32 { x: 0.680, y: 0.320, Y: 1.0 },
-                XYYf32 { x: 0.265, y: 0.690, Y: 1.0 },
-                XYYf32 { x: 0.150, y: 0.060 , Y: 1.0},
-                XYYf32 {
-                    x: 0.314,
-                    y: 0.351,
-                    Y: 1.0,
-                },
-                Box::new(|c: RGBf32| c.powf(1.0 / 2.6)),
-                Box::new(|c: RGBf32| c.powf(2.6)),
-            )
-        };
-
-        /// P3 D65
-        /// Data taken from https://en.wikipedia.org/wiki/DCI-P3
-        pub static ref DCI_P3_D65: ColorSpaceRGB<f32> = {
-            ColorSpaceRGB::new(
-                XYYf32 { x: 0.680, y: 0.320 , Y: 1.0},
-                XYYf32 { x: 0.265, y: 0.690 , Y: 1.0},
-                XYYf32 { x: 0.150, y: 0.060 , Y: 1.0},
-                XYYf32 {
-                    x: 0.3127,
-                    y: 0.3290,
-                    Y: 1.0,
-                },
-                Box::new(|c: RGBf32| c.powf(1.0 / 2.6)),
-                Box::new(|c: RGBf32| c.powf(2.6)),
-            )
-        };
-
-        /// ACES archival color space. AP0 primaries.
-        /// Data taken from https://en.wikipedia.org/wiki/Academy_Color_Encoding_System
-        pub static ref ACES: ColorSpaceRGB<f32> = {
-            ColorSpaceRGB::new_with_specified_matrices(
-                XYYf32 { x: 0.7347, y: 0.2653, Y: 1.0},
-                XYYf32 { x: 0.0000, y: 1.0000, Y: 1.0},
-                XYYf32 { x: 0.0001, y: -0.077, Y: 1.0},
-                XYYf32 {
-                    x: 0.32168,
-                    y: 0.33767,
-                    Y: 1.0,
-                },
-                M3f32::new([
-                    1.0498110175, 0.0000000000, -0.0000974845,
-                    -0.4959030231, 1.3733130458, 0.0982400361,
-                    0.0000000000, 0.0000000000, 0.9912520182,
-                ]),
-                M3f32::new([
-                    0.9525523959, 0.0000000000, 0.0000936786,
-                    0.3439664498, 0.7281660966, -0.0721325464,
-                    0.0000000000, 0.0000000000, 1.0088251844,
-                ]),
-                Box::new(encode::linear),
-                Box::new(decode::linear),
-            )
-        };
-
-        /// ACEScg color space. AP1 primaries.
-        /// Data taken from https://en.wikipedia.org/wiki/Academy_Color_Encoding_System
-        pub static ref ACES_CG: ColorSpaceRGB<f32> = {
-            ColorSpaceRGB::new(
-                XYYf32 { x: 0.713, y: 0.293, Y: 1.0},
-                XYYf32 { x: 0.165, y: 0.830, Y: 1.0},
-                XYYf32 { x: 0.128, y: 0.044, Y: 1.0},
-                XYYf32 {
-                    x: 0.32168,
-                    y: 0.33767,
-                    Y: 1.0,
-                },
-                Box::new(encode::linear),
-                Box::new(decode::linear),
-            )
-        };
-
-        /// Adobe RGB (1998)
-        /// Data taken from https://en.wikipedia.org/wiki/Adobe_RGB_color_space
-        pub static ref ADOBE_RGB_1998: ColorSpaceRGB<f32> = {
-            ColorSpaceRGB::new_with_specified_matrices(
-                XYYf32 { x: 0.6400, y: 0.3300, Y: 1.0},
-                XYYf32 { x: 0.2100, y: 0.7100, Y: 1.0},
-                XYYf32 { x: 0.1500, y: 0.0600, Y: 1.0},
-                XYYf32 {
-                    x: 0.3127,
-                    y: 0.3290,
-                    Y: 1.0,
-                },
-                M3f32::new([
-                    2.04159, -0.56501, -0.34473,
-                    -0.96924, 1.87597, 0.04156,
-                    0.01344, -0.11836, 1.01517,
-                ]),
-                M3f32::new([
-                    0.57667, 0.18556, 0.18823,
-                    0.29734, 0.62736, 0.07529,
-                    0.02703, 0.07069, 0.99134,
-                ]),
-                Box::new(|c: RGBf32| c.powf(1.0 / 2.19921875)),
-                Box::new(|c: RGBf32| c.powf(2.19921875)),
-            )
-        };
-
-        /// ARRI Alexa Wide Gamut.
-        /// Data taken from "Alexa LogC Curve in VFX"
-        pub static ref ALEXA_WIDE_GAMUT: ColorSpaceRGB<f32> = {
-            ColorSpaceRGB::new_with_specified_matrices(
-                XYYf32 { x: 0.6840, y: 0.3130, Y: 1.0},
-                XYYf32 { x: 0.2210, y: 0.8480, Y: 1.0},
-                XYYf32 { x: 0.0861, y: -0.102, Y: 1.0},
-                XYYf32 {
-                    x: 0.3127,
-                    y: 0.3290,
-                    Y: 1.0,
-                },
-                M3f32::new([
-                    1.789066, -0.482534, -0.200076,
-                    -0.639849, 1.396400, 0.194432,
-                    -0.041532, 0.082335, 0.878868,
+                0.0557, -0.2040, 1.0570
                 ]),
-                M3f32::new([
-                    0.638008, 0.214704, 0.097744,
-                    0.291954, 0.823841, -0.115795,
-                    0.002798, -0.067034, 1.153294,
+            M3f64::new([
+                0.4124, 0.3576, 0.1805,
+                0.2126, 0.7152, 0.0722,
+                0.0193, 0.1192, 0.9505
                 ]),
-                Box::new(encode::alexa_logc_v3),
-                Box::new(decode::alexa_logc_v3),
-            )
-        };
-
-    }
+            TransferCurve::Srgb,
+        )
+    };
+
+    /// sRGB - derived matrices
+    /// Data taken https://en.wikipedia.org/wiki/SRGB
+    SRGB_DRV = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.64, y: 0.33, Y: 1.0 },
+            XYYf64 { x: 0.30, y: 0.60, Y: 1.0 },
+            XYYf64 { x: 0.15, y: 0.06, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::Srgb,
+        )
+    };
+
+    /// ITU-R Rec. BT.709
+    /// Data taken from https://en.wikipedia.org/wiki/Rec._709
+    ITUR_BT709 = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.64, y: 0.33, Y: 1.0 },
+            XYYf64 { x: 0.30, y: 0.60, Y: 1.0 },
+            XYYf64 { x: 0.15, y: 0.06, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::Bt709,
+        )
+    };
+
+    /// ITU-R Rec. BT.2020
+    /// Data taken from https://en.wikipedia.org/wiki/Rec._2020
+    /// See also https://www.itu.int/rec/R-REC-BT.1886-0-201103-I
+    ITUR_BT2020 = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.708, y: 0.292, Y: 1.0 },
+            XYYf64 { x: 0.17, y: 0.797, Y: 1.0 },
+            XYYf64 { x: 0.131, y: 0.046, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::Bt2020,
+        )
+    };
+
+    /// ITU-R Rec. BT.2100 with the SMPTE ST 2084 (PQ) transfer function. Linear values are
+    /// normalised so that 1.0 is 10000 cd/m².
+    /// Data taken from https://www.itu.int/rec/R-REC-BT.2100
+    ITUR_BT2100_PQ = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.708, y: 0.292, Y: 1.0 },
+            XYYf64 { x: 0.17, y: 0.797, Y: 1.0 },
+            XYYf64 { x: 0.131, y: 0.046, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::Pq,
+        )
+    };
+
+    /// ITU-R Rec. BT.2100 with the ARIB STD-B67 (HLG) transfer
+    /// function. Linear values are normalised scene light; use [hlg_ootf]
+    /// to obtain display light.
+    /// Data taken from https://www.itu.int/rec/R-REC-BT.2100
+    ITUR_BT2100_HLG = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.708, y: 0.292, Y: 1.0 },
+            XYYf64 { x: 0.17, y: 0.797, Y: 1.0 },
+            XYYf64 { x: 0.131, y: 0.046, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::Hlg,
+        )
+    };
+
+    /// DCI-P3
+    /// Data taken from https://en.wikipedia.org/wiki/DCI-P3
+    DCI_P3 = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.680, y: 0.320, Y: 1.0 },
+            XYYf64 { x: 0.265, y: 0.690, Y: 1.0 },
+            XYYf64 { x: 0.150, y: 0.060 , Y: 1.0},
+            XYYf64 {
+                x: 0.314,
+                y: 0.351,
+                Y: 1.0,
+            },
+            TransferCurve::Gamma(2.6),
+        )
+    };
+
+    /// P3 D65
+    /// Data taken from https://en.wikipedia.org/wiki/DCI-P3
+    DCI_P3_D65 = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.680, y: 0.320 , Y: 1.0},
+            XYYf64 { x: 0.265, y: 0.690 , Y: 1.0},
+            XYYf64 { x: 0.150, y: 0.060 , Y: 1.0},
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::Gamma(2.6),
+        )
+    };
+
+    /// P3 D65 with the SMPTE ST 2084 (PQ) transfer function. Linear values
+    /// are normalised so that 1.0 is 10000 cd/m².
+    /// Data taken from https://en.wikipedia.org/wiki/DCI-P3
+    DCI_P3_D65_PQ = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.680, y: 0.320, Y: 1.0 },
+            XYYf64 { x: 0.265, y: 0.690, Y: 1.0 },
+            XYYf64 { x: 0.150, y: 0.060, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::Pq,
+        )
+    };
+
+    /// ACES archival color space. AP0 primaries.
+    /// Data taken from https://en.wikipedia.org/wiki/Academy_Color_Encoding_System
+    ACES = {
+        ColorSpaceRGB::new_with_specified_matrices(
+            XYYf64 { x: 0.7347, y: 0.2653, Y: 1.0},
+            XYYf64 { x: 0.0000, y: 1.0000, Y: 1.0},
+            XYYf64 { x: 0.0001, y: -0.077, Y: 1.0},
+            XYYf64 {
+                x: 0.32168,
+                y: 0.33767,
+                Y: 1.0,
+            },
+            M3f64::new([
+                1.0498110175, 0.0000000000, -0.0000974845,
+                -0.4959030231, 1.3733130458, 0.0982400361,
+                0.0000000000, 0.0000000000, 0.9912520182,
+            ]),
+            M3f64::new([
+                0.9525523959, 0.0000000000, 0.0000936786,
+                0.3439664498, 0.7281660966, -0.0721325464,
+                0.0000000000, 0.0000000000, 1.0088251844,
+            ]),
+            TransferCurve::Linear,
+        )
+    };
+
+    /// ACEScg color space. AP1 primaries.
+    /// Data taken from https://en.wikipedia.org/wiki/Academy_Color_Encoding_System
+    ACES_CG = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.713, y: 0.293, Y: 1.0},
+            XYYf64 { x: 0.165, y: 0.830, Y: 1.0},
+            XYYf64 { x: 0.128, y: 0.044, Y: 1.0},
+            XYYf64 {
+                x: 0.32168,
+                y: 0.33767,
+                Y: 1.0,
+            },
+            TransferCurve::Linear,
+        )
+    };
+
+    /// ACEScc color space. AP1 primaries with a pure log encoding.
+    /// Data taken from https://en.wikipedia.org/wiki/Academy_Color_Encoding_System
+    ACES_CC = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.713, y: 0.293, Y: 1.0},
+            XYYf64 { x: 0.165, y: 0.830, Y: 1.0},
+            XYYf64 { x: 0.128, y: 0.044, Y: 1.0},
+            XYYf64 {
+                x: 0.32168,
+                y: 0.33767,
+                Y: 1.0,
+            },
+            TransferCurve::AcesCc,
+        )
+    };
+
+    /// ACEScct color space. AP1 primaries with a log encoding that has a
+    /// linear toe.
+    /// Data taken from https://en.wikipedia.org/wiki/Academy_Color_Encoding_System
+    ACES_CCT = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.713, y: 0.293, Y: 1.0},
+            XYYf64 { x: 0.165, y: 0.830, Y: 1.0},
+            XYYf64 { x: 0.128, y: 0.044, Y: 1.0},
+            XYYf64 {
+                x: 0.32168,
+                y: 0.33767,
+                Y: 1.0,
+            },
+            TransferCurve::AcesCct,
+        )
+    };
+
+    /// ACESproxy color space. AP1 primaries with a 10-bit quantised log
+    /// encoding.
+    /// Data taken from https://en.wikipedia.org/wiki/Academy_Color_Encoding_System
+    ACES_PROXY = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.713, y: 0.293, Y: 1.0},
+            XYYf64 { x: 0.165, y: 0.830, Y: 1.0},
+            XYYf64 { x: 0.128, y: 0.044, Y: 1.0},
+            XYYf64 {
+                x: 0.32168,
+                y: 0.33767,
+                Y: 1.0,
+            },
+            TransferCurve::AcesProxy,
+        )
+    };
+
+    /// Adobe RGB (1998)
+    /// Data taken from
+    /// https://www.adobe.com/digitalimag/pdfs/AdobeRGB1998.pdf
+    ADOBE_RGB_1998 = {
+        ColorSpaceRGB::new_with_specified_matrices(
+            XYYf64 { x: 0.6400, y: 0.3300, Y: 1.0},
+            XYYf64 { x: 0.2100, y: 0.7100, Y: 1.0},
+            XYYf64 { x: 0.1500, y: 0.0600, Y: 1.0},
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            M3f64::new([
+                2.04159, -0.56501, -0.34473,
+                -0.96924, 1.87597, 0.04156,
+                0.01344, -0.11836, 1.01517,
+            ]),
+            M3f64::new([
+                0.57667, 0.18556, 0.18823,
+                0.29734, 0.62736, 0.07529,
+                0.02703, 0.07069, 0.99134,
+            ]),
+            TransferCurve::Gamma(2.19921875),
+        )
+    };
+
+    /// Display P3. P3 primaries with a D65 white and the sRGB curve.
+    /// Data taken from https://en.wikipedia.org/wiki/DCI-P3
+    DISPLAY_P3 = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.680, y: 0.320, Y: 1.0 },
+            XYYf64 { x: 0.265, y: 0.690, Y: 1.0 },
+            XYYf64 { x: 0.150, y: 0.060, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::Srgb,
+        )
+    };
+
+    /// DCI-P3+, Canon's extension of DCI-P3 with the DCI white point.
+    /// Data taken from https://github.com/colour-science/colour
+    DCI_P3_P = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.740, y: 0.270, Y: 1.0 },
+            XYYf64 { x: 0.220, y: 0.780, Y: 1.0 },
+            XYYf64 { x: 0.090, y: -0.090, Y: 1.0 },
+            XYYf64 {
+                x: 0.314,
+                y: 0.351,
+                Y: 1.0,
+            },
+            TransferCurve::Gamma(2.6),
+        )
+    };
+
+    /// ProPhoto RGB, also known as ROMM RGB.
+    /// Data taken from ISO 22028-2:2013
+    PRO_PHOTO_RGB = {
+        ColorSpaceRGB::new_with_specified_matrices(
+            XYYf64 { x: 0.7347, y: 0.2653, Y: 1.0 },
+            XYYf64 { x: 0.1596, y: 0.8404, Y: 1.0 },
+            XYYf64 { x: 0.0366, y: 0.0001, Y: 1.0 },
+            XYYf64 {
+                x: 0.3457,
+                y: 0.3585,
+                Y: 1.0,
+            },
+            M3f64::new([
+                1.3460, -0.2556, -0.0511,
+                -0.5446, 1.5082, 0.0205,
+                0.0000, 0.0000, 1.2123,
+            ]),
+            M3f64::new([
+                0.7977, 0.1352, 0.0313,
+                0.2880, 0.7119, 0.0001,
+                0.0000, 0.0000, 0.8249,
+            ]),
+            TransferCurve::ProPhoto,
+        )
+    };
+
+    /// Beta RGB.
+    /// Data taken from http://www.brucelindbloom.com/index.html?BetaRGB.html
+    BETA_RGB = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.6888, y: 0.3112, Y: 1.0 },
+            XYYf64 { x: 0.1986, y: 0.7551, Y: 1.0 },
+            XYYf64 { x: 0.1265, y: 0.0352, Y: 1.0 },
+            XYYf64 {
+                x: 0.3457,
+                y: 0.3585,
+                Y: 1.0,
+            },
+            TransferCurve::Gamma(2.2),
+        )
+    };
+
+    /// Sharp RGB, with an equal-energy white and a linear encoding.
+    /// Data taken from Ward, "Picture Perfect RGB Rendering Using Spectral
+    /// Prefiltering and Sharp Color Primaries"
+    SHARP_RGB = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.6898, y: 0.3206, Y: 1.0 },
+            XYYf64 { x: 0.0736, y: 0.9003, Y: 1.0 },
+            XYYf64 { x: 0.1166, y: 0.0374, Y: 1.0 },
+            XYYf64 {
+                x: 1.0 / 3.0,
+                y: 1.0 / 3.0,
+                Y: 1.0,
+            },
+            TransferCurve::Linear,
+        )
+    };
+
+    /// ECI RGB v2, with the CIE L* curve.
+    /// Data taken from http://www.eci.org/en/downloads
+    ECI_RGB_V2 = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.670, y: 0.330, Y: 1.0 },
+            XYYf64 { x: 0.210, y: 0.710, Y: 1.0 },
+            XYYf64 { x: 0.140, y: 0.080, Y: 1.0 },
+            XYYf64 {
+                x: 0.3457,
+                y: 0.3585,
+                Y: 1.0,
+            },
+            TransferCurve::LStar,
+        )
+    };
+
+    /// Adobe Wide Gamut RGB.
+    /// Data taken from https://en.wikipedia.org/wiki/Wide-gamut_RGB_color_space
+    WIDE_GAMUT_RGB = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.7347, y: 0.2653, Y: 1.0 },
+            XYYf64 { x: 0.1152, y: 0.8264, Y: 1.0 },
+            XYYf64 { x: 0.1566, y: 0.0177, Y: 1.0 },
+            XYYf64 {
+                x: 0.3457,
+                y: 0.3585,
+                Y: 1.0,
+            },
+            TransferCurve::Gamma(2.19921875),
+        )
+    };
+
+    /// Apple RGB.
+    /// Data taken from http://www.brucelindbloom.com/index.html?WorkingSpaceInfo.html
+    APPLE_RGB = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.6250, y: 0.3400, Y: 1.0 },
+            XYYf64 { x: 0.2800, y: 0.5950, Y: 1.0 },
+            XYYf64 { x: 0.1550, y: 0.0700, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::Gamma(1.8),
+        )
+    };
+
+    /// NTSC (1953), with illuminant C and the ITU-R BT.470 System M gamma.
+    /// Data taken from ITU-R Rec. BT.470-6
+    NTSC_1953 = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.67, y: 0.33, Y: 1.0 },
+            XYYf64 { x: 0.21, y: 0.71, Y: 1.0 },
+            XYYf64 { x: 0.14, y: 0.08, Y: 1.0 },
+            XYYf64 {
+                x: 0.31006,
+                y: 0.31616,
+                Y: 1.0,
+            },
+            TransferCurve::Gamma(2.2),
+        )
+    };
+
+    /// SMPTE-C, with the SMPTE 170M curve, which is the same as BT.709's.
+    /// Data taken from SMPTE RP 145:2004
+    SMPTE_C = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.630, y: 0.340, Y: 1.0 },
+            XYYf64 { x: 0.310, y: 0.595, Y: 1.0 },
+            XYYf64 { x: 0.155, y: 0.070, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::Bt709,
+        )
+    };
+
+    /// PAL/SECAM, with the ITU-R BT.470 System B, G gamma.
+    /// Data taken from ITU-R Rec. BT.470-6
+    PAL_SECAM = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.64, y: 0.33, Y: 1.0 },
+            XYYf64 { x: 0.29, y: 0.60, Y: 1.0 },
+            XYYf64 { x: 0.15, y: 0.06, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::Gamma(2.8),
+        )
+    };
+
+    /// ARRI Alexa Wide Gamut.
+    /// Data taken from "Alexa LogC Curve in VFX"
+    ALEXA_WIDE_GAMUT = {
+        ColorSpaceRGB::new_with_specified_matrices(
+            XYYf64 { x: 0.6840, y: 0.3130, Y: 1.0},
+            XYYf64 { x: 0.2210, y: 0.8480, Y: 1.0},
+            XYYf64 { x: 0.0861, y: -0.102, Y: 1.0},
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            M3f64::new([
+                1.789066, -0.482534, -0.200076,
+                -0.639849, 1.396400, 0.194432,
+                -0.041532, 0.082335, 0.878868,
+            ]),
+            M3f64::new([
+                0.638008, 0.214704, 0.097744,
+                0.291954, 0.823841, -0.115795,
+                0.002798, -0.067034, 1.153294,
+            ]),
+            TransferCurve::AlexaLogCV3,
+        )
+    };
+
+    /// ARRI Wide Gamut 4 with the LogC4 curve.
+    /// Data taken from "ARRI LogC4 Logarithmic Color Space Specification"
+    ALEXA_WIDE_GAMUT_4 = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.7347, y: 0.2653, Y: 1.0 },
+            XYYf64 { x: 0.1424, y: 0.8576, Y: 1.0 },
+            XYYf64 { x: 0.0991, y: -0.0308, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::AlexaLogCV4,
+        )
+    };
+
+    /// REDWideGamutRGB with the Log3G10 curve.
+    /// Data taken from "White Paper on REDWideGamutRGB and Log3G10"
+    RED_WIDE_GAMUT_RGB = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.780308, y: 0.304253, Y: 1.0 },
+            XYYf64 { x: 0.121595, y: 1.493994, Y: 1.0 },
+            XYYf64 { x: 0.095612, y: -0.084589, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::RedLog3G10,
+        )
+    };
+
+    /// RED REDcolor with the REDLogFilm curve.
+    /// Primaries derived from the reference data in [crate::colorchecker]
+    RED_COLOR = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.701058563171395, y: 0.330180975940326, Y: 1.0 },
+            XYYf64 { x: 0.298811317306316, y: 0.625169245953132, Y: 1.0 },
+            XYYf64 { x: 0.135038675201354, y: 0.035261776551191, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::RedLogFilm,
+        )
+    };
+
+    /// RED REDcolor2 with the REDLogFilm curve.
+    /// Primaries derived from the reference data in [crate::colorchecker]
+    RED_COLOR2 = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.897407221929775, y: 0.330776225980400, Y: 1.0 },
+            XYYf64 { x: 0.296022094516626, y: 0.684635550900945, Y: 1.0 },
+            XYYf64 { x: 0.099799512883393, y: -0.023000513177988, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::RedLogFilm,
+        )
+    };
+
+    /// RED REDcolor3 with the REDLogFilm curve.
+    /// Primaries derived from the reference data in [crate::colorchecker]
+    RED_COLOR3 = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.702598658589914, y: 0.330185588938484, Y: 1.0 },
+            XYYf64 { x: 0.295782235737268, y: 0.689748258397533, Y: 1.0 },
+            XYYf64 { x: 0.111090529079788, y: -0.004332320984771, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::RedLogFilm,
+        )
+    };
+
+    /// RED REDcolor4 with the REDLogFilm curve.
+    /// Primaries derived from the reference data in [crate::colorchecker]
+    RED_COLOR4 = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.702598154635440, y: 0.330185096210515, Y: 1.0 },
+            XYYf64 { x: 0.295782328047083, y: 0.689748253964859, Y: 1.0 },
+            XYYf64 { x: 0.144459236489795, y: 0.050837720977385, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::RedLogFilm,
+        )
+    };
+
+    /// RED DRAGONcolor with the REDLogFilm curve.
+    /// Primaries derived from the reference data in [crate::colorchecker]
+    DRAGON_COLOR = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.758655892599319, y: 0.330355348611292, Y: 1.0 },
+            XYYf64 { x: 0.294923619810174, y: 0.708053242065117, Y: 1.0 },
+            XYYf64 { x: 0.085961601167585, y: -0.045879436983970, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::RedLogFilm,
+        )
+    };
+
+    /// RED DRAGONcolor2 with the REDLogFilm curve.
+    /// Primaries derived from the reference data in [crate::colorchecker]
+    DRAGON_COLOR2 = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.758656214177603, y: 0.330355835762677, Y: 1.0 },
+            XYYf64 { x: 0.294923887732981, y: 0.708053363192131, Y: 1.0 },
+            XYYf64 { x: 0.144168726866337, y: 0.050357384587120, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::RedLogFilm,
+        )
+    };
+
+    /// Sony S-Gamut3 with the S-Log3 curve.
+    /// Data taken from "Technical Summary for S-Gamut3.Cine/S-Log3 and S-Gamut3/S-Log3"
+    S_GAMUT3 = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.730, y: 0.280, Y: 1.0 },
+            XYYf64 { x: 0.140, y: 0.855, Y: 1.0 },
+            XYYf64 { x: 0.100, y: -0.050, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::SonySLog3,
+        )
+    };
+
+    /// Sony S-Gamut3.Cine with the S-Log3 curve.
+    /// Data taken from "Technical Summary for S-Gamut3.Cine/S-Log3 and S-Gamut3/S-Log3"
+    S_GAMUT3_CINE = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.766, y: 0.275, Y: 1.0 },
+            XYYf64 { x: 0.225, y: 0.800, Y: 1.0 },
+            XYYf64 { x: 0.089, y: -0.087, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::SonySLog3,
+        )
+    };
+
+    /// Panasonic V-Gamut with the V-Log curve.
+    /// Data taken from "V-Log/V-Gamut Reference Manual"
+    V_GAMUT = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.730, y: 0.280, Y: 1.0 },
+            XYYf64 { x: 0.165, y: 0.840, Y: 1.0 },
+            XYYf64 { x: 0.100, y: -0.030, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::PanasonicVLog,
+        )
+    };
+
+    /// Canon Cinema Gamut with the Canon Log 2 curve.
+    /// Data taken from "Canon Log Gamma Curves: Description of the Canon Log,
+    /// Canon Log 2 and Canon Log 3 Gamma Curves"
+    CINEMA_GAMUT_CLOG2 = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.740, y: 0.270, Y: 1.0 },
+            XYYf64 { x: 0.170, y: 1.140, Y: 1.0 },
+            XYYf64 { x: 0.080, y: -0.100, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::CanonCLog2,
+        )
+    };
+
+    /// Canon Cinema Gamut with the Canon Log 3 curve.
+    /// Data taken from "Canon Log Gamma Curves: Description of the Canon Log,
+    /// Canon Log 2 and Canon Log 3 Gamma Curves"
+    CINEMA_GAMUT_CLOG3 = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.740, y: 0.270, Y: 1.0 },
+            XYYf64 { x: 0.170, y: 1.140, Y: 1.0 },
+            XYYf64 { x: 0.080, y: -0.100, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127,
+                y: 0.3290,
+                Y: 1.0,
+            },
+            TransferCurve::CanonCLog3,
+        )
+    };
+
+    /// Blackmagic Wide Gamut with the Blackmagic Film Generation 5 curve.
+    /// Data taken from "Blackmagic Generation 5 Color Science" in the
+    /// DaVinci Resolve documentation
+    BLACKMAGIC_WIDE_GAMUT = {
+        ColorSpaceRGB::new(
+            XYYf64 { x: 0.7177215, y: 0.3171181, Y: 1.0 },
+            XYYf64 { x: 0.2280410, y: 0.8615690, Y: 1.0 },
+            XYYf64 { x: 0.1005841, y: -0.0820452, Y: 1.0 },
+            XYYf64 {
+                x: 0.3127170,
+                y: 0.3290312,
+                Y: 1.0,
+            },
+            TransferCurve::BlackmagicFilmGen5,
+        )
+    };
 }
 
 #[cfg(test)]
@@ -901,6 +2779,15 @@ mod test {
         model: &ColorSpaceRGB<f64>,
         checker_linear: &HashMap<String, RGBf64>,
         checker_encoded: &HashMap<String, RGBf64>,
+    ) {
+        rgb_workout_with_epsilon(model, checker_linear, checker_encoded, 1e-14)
+    }
+
+    fn rgb_workout_with_epsilon(
+        model: &ColorSpaceRGB<f64>,
+        checker_linear: &HashMap<String, RGBf64>,
+        checker_encoded: &HashMap<String, RGBf64>,
+        epsilon: f64,
     ) {
         let xyz_to_rgb_mtx = xyz_to_rgb_matrix(model_f64::SRGB.white, model);
         for (name, xyz_ref) in colorchecker::XYZ_D65.iter() {
@@ -910,10 +2797,7 @@ mod test {
             println!("REF rgb {}: {}", name, checker_linear[name]);
             assert!(rgb.approx_eq(
                 checker_linear[name],
-                F64Margin {
-                    epsilon: 1e-14,
-                    ulps: 2
-                }
+                F64Margin { epsilon, ulps: 2 }
             ));
 
             // encode with the oetf
@@ -922,10 +2806,7 @@ mod test {
             println!("REF encoded {}: {}", name, checker_encoded[name]);
             assert!(rgb.approx_eq(
                 checker_encoded[name],
-                F64Margin {
-                    epsilon: 1e-14,
-                    ulps: 2
-                }
+                F64Margin { epsilon, ulps: 2 }
             ));
 
             // decode back to linear
@@ -934,10 +2815,7 @@ mod test {
             println!("REF decoded {}: {}", name, checker_linear[name]);
             assert!(rgb.approx_eq(
                 checker_linear[name],
-                F64Margin {
-                    epsilon: 1e-14,
-                    ulps: 2
-                }
+                F64Margin { epsilon, ulps: 2 }
             ));
         }
     }
@@ -1031,6 +2909,269 @@ mod test {
         );
     }
 
+    #[test]
+    fn checker_acescc() {
+        rgb_workout(
+            &model_f64::ACES_CC,
+            &colorchecker::ACES_CC_LINEAR,
+            &colorchecker::ACES_CC_ENCODED,
+        );
+    }
+
+    #[test]
+    fn checker_acescct() {
+        rgb_workout(
+            &model_f64::ACES_CCT,
+            &colorchecker::ACES_CCT_LINEAR,
+            &colorchecker::ACES_CCT_ENCODED,
+        );
+    }
+
+    #[test]
+    fn aces_log_toe() {
+        // values below the breakpoints of ACEScc and ACEScct
+        for x in [1e-6f64, 1e-5, 2e-5, 1e-3, 0.0078125].iter() {
+            assert!((decode::acescc_t(encode::acescc_t(*x)) - x).abs() < 1e-15);
+            assert!(
+                (decode::acescct_t(encode::acescct_t(*x)) - x).abs() < 1e-15
+            );
+        }
+        // ACEScct is continuous at its breakpoint
+        let x_brk = 0.0078125f64;
+        assert!(
+            (encode::acescct_t(x_brk) - (x_brk.log2() + 9.72) / 17.52).abs()
+                < 1e-12
+        );
+    }
+
+    #[test]
+    fn checker_acesproxy() {
+        // ACESproxy is quantised so it does not round trip exactly. Each
+        // code value is 1/50 of a stop.
+        let model = &model_f64::ACES_PROXY;
+        for (name, rgb) in colorchecker::ACES_PROXY_LINEAR.iter() {
+            let encoded = model.encode(*rgb);
+            assert!(encoded.approx_eq(
+                colorchecker::ACES_PROXY_ENCODED[name],
+                F64Margin {
+                    epsilon: 1e-14,
+                    ulps: 2
+                }
+            ));
+
+            let decoded = model.decode(encoded);
+            let max_error = 2.0f64.powf(0.5 / 50.0);
+            assert!(
+                decoded.r / rgb.r < max_error && rgb.r / decoded.r < max_error
+            );
+            assert!(
+                decoded.g / rgb.g < max_error && rgb.g / decoded.g < max_error
+            );
+            assert!(
+                decoded.b / rgb.b < max_error && rgb.b / decoded.b < max_error
+            );
+        }
+    }
+
+    #[test]
+    fn camera_log_curves() {
+        type Curve = fn(f64) -> f64;
+        // 18% grey as given in each manufacturer's specification
+        let curves: [(Curve, Curve, f64); 8] = [
+            (encode::red_log3g10_t, decode::red_log3g10_t, 1.0 / 3.0),
+            (encode::red_logfilm_t, decode::red_logfilm_t, 0.457319613),
+            (encode::sony_slog3_t, decode::sony_slog3_t, 420.0 / 1023.0),
+            (
+                encode::panasonic_vlog_t,
+                decode::panasonic_vlog_t,
+                0.423311449,
+            ),
+            (encode::canon_clog2_t, decode::canon_clog2_t, 0.398254693),
+            (encode::canon_clog3_t, decode::canon_clog3_t, 0.343389370),
+            (
+                encode::blackmagic_film_gen5_t,
+                decode::blackmagic_film_gen5_t,
+                0.383561644,
+            ),
+            (
+                encode::alexa_logc_v4_t,
+                decode::alexa_logc_v4_t,
+                0.278395837,
+            ),
+        ];
+
+        for (encode, decode, grey) in curves.iter() {
+            assert!((encode(0.18) - grey).abs() < 1e-6);
+            // V-Log and Film Gen5 are very slightly discontinuous at their
+            // breakpoints, so the values avoid them
+            for x in [-0.01, 0.0, 0.002, 0.05, 0.18, 1.0, 10.0].iter() {
+                assert!(
+                    (decode(encode(*x)) - x).abs() < 1e-12 * x.abs().max(1.0)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn checker_red() {
+        // the REDLogFilm curve is steep near its black offset, which cyan's
+        // red channel falls close to
+        rgb_workout_with_epsilon(
+            &model_f64::RED_COLOR,
+            &colorchecker::RED_COLOR_LINEAR,
+            &colorchecker::RED_COLOR_ENCODED,
+            1e-12,
+        );
+        rgb_workout_with_epsilon(
+            &model_f64::RED_COLOR2,
+            &colorchecker::RED_COLOR2_LINEAR,
+            &colorchecker::RED_COLOR2_ENCODED,
+            1e-12,
+        );
+        rgb_workout_with_epsilon(
+            &model_f64::RED_COLOR3,
+            &colorchecker::RED_COLOR3_LINEAR,
+            &colorchecker::RED_COLOR3_ENCODED,
+            1e-12,
+        );
+        rgb_workout_with_epsilon(
+            &model_f64::RED_COLOR4,
+            &colorchecker::RED_COLOR4_LINEAR,
+            &colorchecker::RED_COLOR4_ENCODED,
+            1e-12,
+        );
+        rgb_workout_with_epsilon(
+            &model_f64::DRAGON_COLOR,
+            &colorchecker::DRAGON_COLOR_LINEAR,
+            &colorchecker::DRAGON_COLOR_ENCODED,
+            1e-12,
+        );
+        rgb_workout_with_epsilon(
+            &model_f64::DRAGON_COLOR2,
+            &colorchecker::DRAGON_COLOR2_LINEAR,
+            &colorchecker::DRAGON_COLOR2_ENCODED,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn checker_reference_spaces() {
+        rgb_workout(
+            &model_f64::PRO_PHOTO_RGB,
+            &colorchecker::PRO_PHOTO_RGB_LINEAR,
+            &colorchecker::PRO_PHOTO_RGB_ENCODED,
+        );
+        rgb_workout(
+            &model_f64::BETA_RGB,
+            &colorchecker::BETA_RGB_LINEAR,
+            &colorchecker::BETA_RGB_ENCODED,
+        );
+        rgb_workout(
+            &model_f64::SHARP_RGB,
+            &colorchecker::SHARP_RGB_LINEAR,
+            &colorchecker::SHARP_RGB_ENCODED,
+        );
+        rgb_workout(
+            &model_f64::DCI_P3_P,
+            &colorchecker::DCI_P3_P_LINEAR,
+            &colorchecker::DCI_P3_P_ENCODED,
+        );
+    }
+
+    #[test]
+    fn checker_reference_spaces_round_trip() {
+        for model in [
+            &*model_f64::DISPLAY_P3,
+            &*model_f64::ECI_RGB_V2,
+            &*model_f64::WIDE_GAMUT_RGB,
+            &*model_f64::APPLE_RGB,
+            &*model_f64::NTSC_1953,
+            &*model_f64::SMPTE_C,
+            &*model_f64::PAL_SECAM,
+        ]
+        .iter()
+        {
+            let xyz_to_rgb_mtx =
+                xyz_to_rgb_matrix(model_f64::SRGB.white, model);
+            let rgb_to_xyz_mtx =
+                rgb_to_xyz_matrix(model_f64::SRGB.white, model);
+            for xyz_ref in colorchecker::XYZ_D65.values() {
+                let rgb = xyz_to_rgb(&xyz_to_rgb_mtx, *xyz_ref);
+                // the pure power curves are undefined for colours outside
+                // the smaller gamuts
+                if rgb.r < 0.0 || rgb.g < 0.0 || rgb.b < 0.0 {
+                    continue;
+                }
+
+                let rgb = model.decode(model.encode(rgb));
+                let xyz = rgb_to_xyz(&rgb_to_xyz_mtx, rgb);
+                assert!((xyz.x - xyz_ref.x).abs() < 1e-12);
+                assert!((xyz.y - xyz_ref.y).abs() < 1e-12);
+                assert!((xyz.z - xyz_ref.z).abs() < 1e-12);
+            }
+        }
+
+        // Display P3 shares the P3 D65 gamut but uses the sRGB curve
+        assert_eq!(
+            model_f64::DISPLAY_P3.xf_xyz_to_rgb.x,
+            model_f64::DCI_P3_D65.xf_xyz_to_rgb.x
+        );
+        assert!((encode::lstar_t(1.0f64) - 1.0).abs() < 1e-15);
+        assert!((encode::lstar_t(0.18f64) - 0.494961076).abs() < 1e-9);
+    }
+
+    #[test]
+    fn published_npm() {
+        // RGB to XYZ matrices from Bruce Lindbloom's working space tables,
+        // and from Apple for Display P3. Lindbloom computes his matrices
+        // from ASTM white points and rounds the Wide Gamut primaries to 3
+        // places, which moves individual entries by up to 6e-4.
+        #[rustfmt::skip]
+        let published: [(&ColorSpaceRGB<f64>, [f64; 9], f64); 7] = [
+            (&model_f64::DISPLAY_P3, [
+                0.48657095, 0.26566769, 0.19821729,
+                0.22897456, 0.69173852, 0.07928691,
+                0.00000000, 0.04511338, 1.04394437,
+            ], 1e-7),
+            (&model_f64::ECI_RGB_V2, [
+                0.6502043, 0.1780774, 0.1359384,
+                0.3202499, 0.6020711, 0.0776791,
+                0.0000000, 0.0678390, 0.7573710,
+            ], 1e-3),
+            (&model_f64::WIDE_GAMUT_RGB, [
+                0.7161046, 0.1009296, 0.1471858,
+                0.2581874, 0.7249378, 0.0168748,
+                0.0000000, 0.0517813, 0.7734287,
+            ], 1e-3),
+            (&model_f64::APPLE_RGB, [
+                0.4497288, 0.3162486, 0.1844926,
+                0.2446525, 0.6720283, 0.0833192,
+                0.0251848, 0.1411824, 0.9224628,
+            ], 1e-3),
+            (&model_f64::NTSC_1953, [
+                0.6068909, 0.1735011, 0.2003480,
+                0.2989164, 0.5865990, 0.1144845,
+                0.0000000, 0.0660957, 1.1162243,
+            ], 1e-3),
+            (&model_f64::SMPTE_C, [
+                0.3935891, 0.3652497, 0.1916313,
+                0.2124132, 0.7010437, 0.0865432,
+                0.0187423, 0.1119313, 0.9581563,
+            ], 1e-3),
+            (&model_f64::PAL_SECAM, [
+                0.4306190, 0.3415419, 0.1783091,
+                0.2220379, 0.7066384, 0.0713236,
+                0.0201853, 0.1295504, 0.9390944,
+            ], 1e-3),
+        ];
+
+        for (model, m, epsilon) in published.iter() {
+            for (a, b) in model.xf_rgb_to_xyz.x.iter().zip(m.iter()) {
+                assert!((a - b).abs() < *epsilon, "{} != {}", a, b);
+            }
+        }
+    }
+
     #[test]
     fn checker_dcip3() {
         rgb_workout(
@@ -1054,4 +3195,228 @@ mod test {
             ));
         }
     }
+
+    #[test]
+    fn pq_hlg() {
+        // reference values from the colour-science library
+        assert!(
+            (encode::pq_t(100.0f64 / 10000.0) - 0.508078421517399).abs()
+                < 1e-12
+        );
+        assert!(
+            (encode::pq_t(1000.0f64 / 10000.0) - 0.751827096247041).abs()
+                < 1e-12
+        );
+        assert!((encode::pq_t(1.0f64) - 1.0).abs() < 1e-12);
+        assert!((decode::pq_t(0.508078421517399f64) - 0.01).abs() < 1e-12);
+
+        assert!((encode::hlg_t(1.0f64 / 12.0) - 0.5).abs() < 1e-12);
+        assert!((encode::hlg_t(1.0f64) - 1.0).abs() < 1e-7);
+        assert!((decode::hlg_t(0.5f64) - 1.0 / 12.0).abs() < 1e-12);
+
+        assert!((hlg_system_gamma(1000.0f64) - 1.2).abs() < 1e-12);
+        assert!(
+            (hlg_system_gamma(2000.0f64) - 1.326432598178872).abs() < 1e-12
+        );
+
+        let gamma = hlg_system_gamma(1000.0f64);
+        let white = decode::hlg_eotf(RGBf64::from_scalar(1.0), gamma);
+        assert!((white.r - 1.0).abs() < 1e-7);
+        assert!((white.g - 1.0).abs() < 1e-7);
+        assert!((white.b - 1.0).abs() < 1e-7);
+
+        for model in [
+            &*model_f64::ITUR_BT2100_PQ,
+            &*model_f64::ITUR_BT2100_HLG,
+            &*model_f64::DCI_P3_D65_PQ,
+        ]
+        .iter()
+        {
+            for rgb in colorchecker::ITUR_BT2020_LINEAR.values() {
+                let rgb_2 = model.decode(model.encode(*rgb));
+                assert!((rgb.r - rgb_2.r).abs() < 1e-12);
+                assert!((rgb.g - rgb_2.g).abs() < 1e-12);
+                assert!((rgb.b - rgb_2.b).abs() < 1e-12);
+            }
+        }
+
+        for rgb in colorchecker::ITUR_BT2020_LINEAR.values() {
+            let rgb_2 =
+                decode::hlg_eotf(encode::hlg_inverse_eotf(*rgb, gamma), gamma);
+            assert!((rgb.r - rgb_2.r).abs() < 1e-12);
+            assert!((rgb.g - rgb_2.g).abs() < 1e-12);
+            assert!((rgb.b - rgb_2.b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn transfer_curves() {
+        let xs = [0.0f64, 0.001, 0.01, 0.05, 0.18, 0.5, 1.0];
+
+        // sRGB as an ICC type 3 curve
+        let icc = IccParametricCurve::type_3(
+            2.4,
+            1.0 / 1.055,
+            0.055 / 1.055,
+            1.0 / 12.92,
+            0.04045,
+        );
+        for x in xs.iter() {
+            assert!((icc.decode(*x) - decode::srgb_t(*x)).abs() < 1e-12);
+            assert!((icc.encode(*x) - encode::srgb_t(*x)).abs() < 1e-12);
+        }
+
+        // BT.1886 with a zero black level is a pure 2.4 gamma
+        let bt1886 = TransferCurve::Bt1886 { l_w: 1.0, l_b: 0.0 };
+        let gamma = TransferCurve::Gamma(2.4);
+        for x in xs.iter() {
+            let c = RGBf64::from_scalar(*x);
+            assert!((bt1886.decode(c).r - gamma.decode(c).r).abs() < 1e-14);
+            assert!((bt1886.encode(c).r - gamma.encode(c).r).abs() < 1e-14);
+        }
+        let bt1886 = TransferCurve::Bt1886 {
+            l_w: 100.0,
+            l_b: 0.1,
+        };
+        let black = bt1886.decode(RGBf64::from_scalar(0.0));
+        let white = bt1886.decode(RGBf64::from_scalar(1.0));
+        assert!((black.r - 0.1).abs() < 1e-12);
+        assert!((white.r - 100.0).abs() < 1e-12);
+
+        // ALEXA LogC v3 as a generic log curve
+        let logc = LogParameters {
+            base: 10.0,
+            log_side_slope: 0.247190,
+            log_side_offset: 0.385537,
+            lin_side_slope: 5.555556,
+            lin_side_offset: 0.052272,
+            lin_side_break: Some(0.010591),
+        };
+        for x in [-0.01, 0.0, 0.005].iter().chain(xs.iter()) {
+            let y = logc.encode(*x);
+            assert!((y - encode::alexa_logc_v3_t(*x)).abs() < 1e-5);
+            assert!((logc.decode(y) - *x).abs() < 1e-12);
+        }
+
+        // every variant round trips
+        let curves = [
+            TransferCurve::Linear,
+            TransferCurve::Srgb,
+            TransferCurve::Gamma(2.2),
+            TransferCurve::Bt709,
+            TransferCurve::Bt2020,
+            TransferCurve::Bt1886 {
+                l_w: 100.0,
+                l_b: 0.1,
+            },
+            TransferCurve::IccParametric(icc),
+            TransferCurve::Log(logc),
+            TransferCurve::ProPhoto,
+            TransferCurve::LStar,
+            TransferCurve::AlexaLogCV3,
+            TransferCurve::AlexaLogCV4,
+            TransferCurve::RedLog3G10,
+            TransferCurve::RedLogFilm,
+            TransferCurve::SonySLog3,
+            TransferCurve::PanasonicVLog,
+            TransferCurve::CanonCLog2,
+            TransferCurve::CanonCLog3,
+            TransferCurve::BlackmagicFilmGen5,
+            TransferCurve::AcesCc,
+            TransferCurve::AcesCct,
+            TransferCurve::Pq,
+            TransferCurve::Hlg,
+            TransferCurve::custom(
+                "cube",
+                |c: RGBf64| c.powf(1.0 / 3.0),
+                |c: RGBf64| c.powf(3.0),
+            ),
+        ];
+        for curve in curves.iter() {
+            let c = RGBf64::new(0.05, 0.18, 0.5);
+            let c_2 = curve.decode(curve.encode(c));
+            assert!((c.r - c_2.r).abs() < 1e-12, "{:?}", curve);
+            assert!((c.g - c_2.g).abs() < 1e-12, "{:?}", curve);
+            assert!((c.b - c_2.b).abs() < 1e-12, "{:?}", curve);
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn transfer_function_constructors() {
+        let cs = ColorSpaceRGB::<f64>::from_transfer_functions(
+            model_f64::SRGB.red,
+            model_f64::SRGB.green,
+            model_f64::SRGB.blue,
+            model_f64::SRGB.white,
+            Box::new(encode::srgb),
+            Box::new(decode::srgb),
+        );
+        assert_eq!(cs.xf_xyz_to_rgb, model_f64::SRGB_DRV.xf_xyz_to_rgb);
+
+        for rgb in colorchecker::SRGB_LINEAR.values() {
+            assert_eq!(cs.oetf()(*rgb), model_f64::SRGB.encode(*rgb));
+            assert_eq!(cs.eotf()(cs.encode(*rgb)), cs.decode(cs.encode(*rgb)));
+            assert_eq!(model_f64::SRGB.oetf()(*rgb), encode::srgb(*rgb));
+        }
+    }
+
+    #[test]
+    fn color_space_compare() {
+        let srgb = model_f64::SRGB.clone();
+        assert_eq!(srgb, *model_f64::SRGB);
+        assert_ne!(srgb, *model_f64::SRGB_DRV);
+        assert_eq!(model_f64::SRGB.transfer, TransferCurve::Srgb);
+        assert_eq!(model_f64::DCI_P3.transfer, TransferCurve::Gamma(2.6));
+        assert!(format!("{:?}", srgb).contains("Srgb"));
+
+        let cube = TransferCurve::custom(
+            "cube",
+            |c: RGBf64| c.powf(1.0 / 3.0),
+            |c: RGBf64| c.powf(3.0),
+        );
+        let other = TransferCurve::custom(
+            "cube",
+            |c: RGBf64| c.powf(1.0 / 3.0),
+            |c: RGBf64| c.powf(3.0),
+        );
+        assert_eq!(cube, cube.clone());
+        assert_ne!(cube, other);
+        assert_eq!(
+            format!("{:?}", cube),
+            "Custom(CustomTransferCurve { name: \"cube\" })"
+        );
+    }
+
+    #[test]
+    fn f64_to_f32() {
+        let srgb: &ColorSpaceRGB<f32> = &model_f32::SRGB_DRV;
+        assert_eq!(srgb.white, XYYf32::new(0.3127, 0.3290, 1.0));
+        assert_eq!(srgb.transfer, TransferCurve::Srgb);
+        for (x32, x64) in srgb
+            .xf_xyz_to_rgb
+            .x
+            .iter()
+            .zip(model_f64::SRGB_DRV.xf_xyz_to_rgb.x.iter())
+        {
+            assert_eq!(*x32, *x64 as f32);
+        }
+
+        let c = RGBf32::new(0.05, 0.18, 0.5);
+        let c_2 = model_f32::ACES_CCT.decode(model_f32::ACES_CCT.encode(c));
+        assert!((c.r - c_2.r).abs() < 1e-6);
+        assert!((c.g - c_2.g).abs() < 1e-6);
+        assert!((c.b - c_2.b).abs() < 1e-6);
+
+        // custom curves keep working after conversion
+        let cube = TransferCurve::custom(
+            "cube",
+            |c: RGBf64| c.powf(1.0 / 3.0),
+            |c: RGBf64| c.powf(3.0),
+        );
+        let cube_32 = TransferCurve::<f32>::from(cube);
+        let c = cube_32.encode(RGBf32::from_scalar(0.125));
+        assert!((c.r - 0.5).abs() < 1e-7);
+        assert!(format!("{:?}", cube_32).contains("cube"));
+    }
 }
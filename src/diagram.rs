@@ -0,0 +1,446 @@
+//! Point lists for plotting chromaticity diagrams: the spectral locus, the
+//! Planckian locus with its isotemperature lines, and the gamut triangles of
+//! RGB color spaces, in either the CIE 1931 `xy` or the CIE 1976 `u'v'`
+//! diagram.
+//!
+//! Points are returned as plain `(x, y)` tuples in the coordinates of the
+//! chosen [Diagram], so they can be passed straight to a plotting library.
+//! [write_svg] draws a complete diagram for quick inspection.
+use crate::cct::cct_to_xy;
+use crate::chromaticity::{SpectralLocus, UV, XYY};
+use crate::cmf::CMF;
+use crate::color_space_rgb::ColorSpaceRGB;
+use crate::math::Real;
+
+use std::io::{self, Write};
+
+/// Color temperatures, in Kelvin, at which [write_svg] draws isotemperature
+/// lines
+const ISOTEMPERATURES: [f64; 9] = [
+    1500.0, 2000.0, 2500.0, 3000.0, 4000.0, 5000.0, 6500.0, 10000.0, 20000.0,
+];
+
+/// Wavelengths, in nm, that [write_svg] labels on the spectral locus
+const LOCUS_LABELS: [f64; 13] = [
+    460.0, 470.0, 480.0, 490.0, 500.0, 510.0, 520.0, 540.0, 560.0, 580.0,
+    600.0, 620.0, 700.0,
+];
+
+/// Stroke colors cycled through for the gamut triangles drawn by
+/// [write_svg]
+const GAMUT_COLORS: [&str; 6] = [
+    "#d62728", "#1f77b4", "#2ca02c", "#9467bd", "#ff7f0e", "#17becf",
+];
+
+/// The chromaticity diagram that points are projected into
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Diagram {
+    /// The CIE 1931 `xy` chromaticity diagram
+    CIE1931,
+    /// The CIE 1976 UCS `u'v'` chromaticity diagram
+    CIE1976,
+}
+
+impl Diagram {
+    /// Project the chromaticity `c` into the coordinates of this diagram
+    pub fn project<C: Into<XYY<f64>>>(&self, c: C) -> (f64, f64) {
+        let c = c.into();
+        match self {
+            Diagram::CIE1931 => (c.x, c.y),
+            Diagram::CIE1976 => {
+                let c = UV::from_xy(c);
+                (c.u, c.v)
+            }
+        }
+    }
+
+    /// Names of the horizontal and vertical axes of this diagram
+    fn axis_names(&self) -> (&'static str, &'static str) {
+        match self {
+            Diagram::CIE1931 => ("x", "y"),
+            Diagram::CIE1976 => ("u'", "v'"),
+        }
+    }
+
+    /// The extent of the axes used by [write_svg]
+    fn extent(&self) -> (f64, f64) {
+        match self {
+            Diagram::CIE1931 => (0.8, 0.9),
+            Diagram::CIE1976 => (0.7, 0.6),
+        }
+    }
+}
+
+/// Returns the spectral locus of `cmf` in `diagram`, one point per
+/// wavelength of `cmf`. Join the last point to the first to close the locus
+/// with the line of purples. The wavelengths of the points are given by
+/// [SpectralLocus::wavelengths].
+pub fn spectral_locus(cmf: &CMF, diagram: Diagram) -> Vec<(f64, f64)> {
+    SpectralLocus::new(cmf)
+        .points()
+        .iter()
+        .map(|&c| diagram.project(c))
+        .collect()
+}
+
+/// Returns `n` points along the Planckian locus in `diagram`, from
+/// `t_min` to `t_max` in Kelvin. The points are spaced evenly in reciprocal
+/// temperature, which spaces them roughly evenly along the locus.
+/// # Panics
+/// Panics if `n` is less than 2, if `t_min` or `t_max` is not positive, or
+/// if `t_max` is less than `t_min`
+pub fn planckian_locus(
+    t_min: f64,
+    t_max: f64,
+    n: usize,
+    diagram: Diagram,
+) -> Vec<(f64, f64)> {
+    if n < 2 {
+        panic!("Planckian locus needs at least 2 points. Got {}", n);
+    }
+    if t_min <= 0.0 || t_max <= 0.0 {
+        panic!(
+            "Planckian locus temperatures must be positive. Got {} to {}",
+            t_min, t_max
+        );
+    }
+    if t_max < t_min {
+        panic!(
+            "Planckian locus t_max must not be less than t_min. Got {} to {}",
+            t_min, t_max
+        );
+    }
+
+    let m_min = 1.0 / t_max;
+    let m_max = 1.0 / t_min;
+    (0..n)
+        .map(|i| {
+            let m = m_max + (m_min - m_max) * i as f64 / (n - 1) as f64;
+            diagram.project(cct_to_xy(1.0 / m, 0.0))
+        })
+        .collect()
+}
+
+/// Returns the end points of the isotemperature line of correlated color
+/// temperature `cct`, in Kelvin, in `diagram`. The line runs from `-duv` to
+/// `duv` either side of the Planckian locus. Isotemperature lines are
+/// straight in both diagrams, so the end points are enough to draw them.
+pub fn isotemperature_line(
+    cct: f64,
+    duv: f64,
+    diagram: Diagram,
+) -> [(f64, f64); 2] {
+    [
+        diagram.project(cct_to_xy(cct, -duv)),
+        diagram.project(cct_to_xy(cct, duv)),
+    ]
+}
+
+/// Returns the red, green and blue primaries of `color_space` in `diagram`
+pub fn gamut_triangle<T>(
+    color_space: &ColorSpaceRGB<T>,
+    diagram: Diagram,
+) -> [(f64, f64); 3]
+where
+    T: Real,
+{
+    let p = |c: XYY<T>| {
+        diagram.project(XYY::new(
+            c.x.to_f64().unwrap(),
+            c.y.to_f64().unwrap(),
+            c.Y.to_f64().unwrap(),
+        ))
+    };
+    [
+        p(color_space.red),
+        p(color_space.green),
+        p(color_space.blue),
+    ]
+}
+
+/// Write an SVG drawing of `diagram` to `w`, showing the spectral locus of
+/// `cmf` with selected wavelengths labelled, the Planckian locus with
+/// isotemperature lines, and the gamut triangle and white point of each of
+/// the named `color_spaces`. The names are escaped for use as XML text.
+/// ```
+/// use colorspace::cmf::CIE_1931_2_DEGREE;
+/// use colorspace::color_space_rgb::model_f64;
+/// use colorspace::diagram::{write_svg, Diagram};
+///
+/// let mut svg = Vec::new();
+/// write_svg(
+///     &mut svg,
+///     Diagram::CIE1976,
+///     &CIE_1931_2_DEGREE,
+///     &[("sRGB", &model_f64::SRGB), ("P3", &model_f64::DCI_P3)],
+/// )
+/// .unwrap();
+/// ```
+pub fn write_svg<W: Write>(
+    w: &mut W,
+    diagram: Diagram,
+    cmf: &CMF,
+    color_spaces: &[(&str, &ColorSpaceRGB<f64>)],
+) -> io::Result<()> {
+    const SCALE: f64 = 800.0;
+    const MARGIN: f64 = 50.0;
+
+    let (x_max, y_max) = diagram.extent();
+    let width = x_max * SCALE + 2.0 * MARGIN;
+    let height = y_max * SCALE + 2.0 * MARGIN;
+    let tx = |x: f64| MARGIN + x * SCALE;
+    let ty = |y: f64| height - MARGIN - y * SCALE;
+    let points = |p: &[(f64, f64)]| {
+        p.iter()
+            .map(|&(x, y)| format!("{:.2},{:.2}", tx(x), ty(y)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    writeln!(
+        w,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{:.0}" height="{:.0}" viewBox="0 0 {:.0} {:.0}" font-family="sans-serif" font-size="12">"#,
+        width, height, width, height
+    )?;
+    writeln!(w, r#"<rect width="100%" height="100%" fill="white"/>"#)?;
+
+    // axes with a grid line every 0.1
+    let (x_name, y_name) = diagram.axis_names();
+    let mut t = 0.0;
+    while t <= x_max + 1e-9 {
+        writeln!(
+            w,
+            r##"<line x1="{0:.2}" y1="{1:.2}" x2="{0:.2}" y2="{2:.2}" stroke="#ddd"/><text x="{0:.2}" y="{3:.2}" text-anchor="middle">{4:.1}</text>"##,
+            tx(t),
+            ty(0.0),
+            ty(y_max),
+            ty(0.0) + 16.0,
+            t
+        )?;
+        t += 0.1;
+    }
+    let mut t = 0.0;
+    while t <= y_max + 1e-9 {
+        writeln!(
+            w,
+            r##"<line x1="{0:.2}" y1="{1:.2}" x2="{2:.2}" y2="{1:.2}" stroke="#ddd"/><text x="{3:.2}" y="{4:.2}" text-anchor="end">{5:.1}</text>"##,
+            tx(0.0),
+            ty(t),
+            tx(x_max),
+            tx(0.0) - 6.0,
+            ty(t) + 4.0,
+            t
+        )?;
+        t += 0.1;
+    }
+    writeln!(
+        w,
+        r#"<text x="{:.2}" y="{:.2}" text-anchor="middle">{}</text>"#,
+        tx(x_max / 2.0),
+        height - 10.0,
+        x_name
+    )?;
+    writeln!(
+        w,
+        r#"<text x="15" y="{:.2}" text-anchor="middle">{}</text>"#,
+        ty(y_max / 2.0),
+        y_name
+    )?;
+
+    // spectral locus closed by the line of purples
+    let locus = SpectralLocus::new(cmf);
+    let locus_points: Vec<_> =
+        locus.points().iter().map(|&c| diagram.project(c)).collect();
+    writeln!(
+        w,
+        r#"<polygon points="{}" fill="none" stroke="black"/>"#,
+        points(&locus_points)
+    )?;
+    for &nm in LOCUS_LABELS.iter() {
+        if let Some(i) = locus
+            .wavelengths()
+            .iter()
+            .position(|&l| (l - nm).abs() < 1e-6)
+        {
+            let (x, y) = locus_points[i];
+            writeln!(
+                w,
+                r#"<circle cx="{:.2}" cy="{:.2}" r="2"/><text x="{:.2}" y="{:.2}" font-size="10">{}</text>"#,
+                tx(x),
+                ty(y),
+                tx(x) + 5.0,
+                ty(y) - 3.0,
+                nm
+            )?;
+        }
+    }
+
+    // Planckian locus with isotemperature lines
+    writeln!(
+        w,
+        r##"<polyline points="{}" fill="none" stroke="#555"/>"##,
+        points(&planckian_locus(1000.0, 25000.0, 100, diagram))
+    )?;
+    for &cct in ISOTEMPERATURES.iter() {
+        let [(x0, y0), (x1, y1)] = isotemperature_line(cct, 0.03, diagram);
+        writeln!(
+            w,
+            r##"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="#555"/><text x="{:.2}" y="{:.2}" font-size="9" fill="#555">{}K</text>"##,
+            tx(x0),
+            ty(y0),
+            tx(x1),
+            ty(y1),
+            tx(x0) + 2.0,
+            ty(y0) + 10.0,
+            cct
+        )?;
+    }
+
+    // gamut triangles, white points and a legend
+    for (i, (name, cs)) in color_spaces.iter().enumerate() {
+        let color = GAMUT_COLORS[i % GAMUT_COLORS.len()];
+        let (wx, wy) = diagram.project(cs.white);
+        writeln!(
+            w,
+            r#"<polygon points="{}" fill="none" stroke="{}" stroke-width="1.5"/>"#,
+            points(&gamut_triangle(cs, diagram)),
+            color
+        )?;
+        writeln!(
+            w,
+            r#"<circle cx="{:.2}" cy="{:.2}" r="3" fill="none" stroke="{}"/>"#,
+            tx(wx),
+            ty(wy),
+            color
+        )?;
+        writeln!(
+            w,
+            r#"<text x="{:.2}" y="{:.2}" fill="{}">{}</text>"#,
+            tx(x_max) - 100.0,
+            ty(y_max) + 16.0 * (i + 1) as f64,
+            color,
+            escape(name)
+        )?;
+    }
+
+    writeln!(w, "</svg>")
+}
+
+/// Escape the characters that may not appear literally in XML text
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cct::xy_to_cct_ohno2013;
+    use crate::cmf::CIE_1931_2_DEGREE;
+    use crate::color_space_rgb::model_f64;
+    use float_cmp::{ApproxEq, F64Margin};
+
+    #[test]
+    fn loci() {
+        let xy = spectral_locus(&CIE_1931_2_DEGREE, Diagram::CIE1931);
+        let uv = spectral_locus(&CIE_1931_2_DEGREE, Diagram::CIE1976);
+        let locus = SpectralLocus::new(&CIE_1931_2_DEGREE);
+        assert_eq!(xy.len(), locus.points().len());
+        assert_eq!(uv.len(), xy.len());
+        for (&(x, y), &(u, v)) in xy.iter().zip(uv.iter()) {
+            let c = UV::from_xy(XYY::new(x, y, 1.0));
+            assert!(u.approx_eq(c.u, F64Margin::default().epsilon(1e-12)));
+            assert!(v.approx_eq(c.v, F64Margin::default().epsilon(1e-12)));
+        }
+
+        let p = planckian_locus(2000.0, 10000.0, 9, Diagram::CIE1931);
+        assert_eq!(p.len(), 9);
+        let (cct, _) = xy_to_cct_ohno2013(XYY::new(p[0].0, p[0].1, 1.0));
+        assert!((cct - 2000.0).abs() < 1.0);
+        let (cct, _) = xy_to_cct_ohno2013(XYY::new(p[8].0, p[8].1, 1.0));
+        assert!((cct - 10000.0).abs() < 5.0);
+
+        let [(x0, y0), (x1, y1)] =
+            isotemperature_line(3000.0, 0.02, Diagram::CIE1931);
+        let (cct, duv) = xy_to_cct_ohno2013(XYY::new(x0, y0, 1.0));
+        assert!((cct - 3000.0).abs() < 1.0);
+        assert!((duv + 0.02).abs() < 1e-4);
+        let (cct, duv) = xy_to_cct_ohno2013(XYY::new(x1, y1, 1.0));
+        assert!((cct - 3000.0).abs() < 1.0);
+        assert!((duv - 0.02).abs() < 1e-4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn planckian_locus_reversed() {
+        planckian_locus(10000.0, 2000.0, 9, Diagram::CIE1931);
+    }
+
+    #[test]
+    #[should_panic]
+    fn planckian_locus_zero_t_max() {
+        planckian_locus(2000.0, 0.0, 9, Diagram::CIE1931);
+    }
+
+    #[test]
+    fn gamut() {
+        let t = gamut_triangle(&*model_f64::SRGB, Diagram::CIE1931);
+        assert!(t[0].0.approx_eq(0.64, F64Margin::default().epsilon(1e-6)));
+        assert!(t[1].1.approx_eq(0.6, F64Margin::default().epsilon(1e-6)));
+        assert!(t[2].0.approx_eq(0.15, F64Margin::default().epsilon(1e-6)));
+
+        // the f32 models give the same triangle to f32 precision
+        let t32 = gamut_triangle(
+            &*crate::color_space_rgb::model_f32::SRGB,
+            Diagram::CIE1976,
+        );
+        let t = gamut_triangle(&*model_f64::SRGB, Diagram::CIE1976);
+        for (a, b) in t.iter().zip(t32.iter()) {
+            assert!((a.0 - b.0).abs() < 1e-6 && (a.1 - b.1).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn svg() {
+        let mut svg = Vec::new();
+        write_svg(
+            &mut svg,
+            Diagram::CIE1931,
+            &CIE_1931_2_DEGREE,
+            &[
+                ("sRGB", &model_f64::SRGB),
+                ("Rec.2020", &model_f64::ITUR_BT2020),
+            ],
+        )
+        .unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        // the spectral locus and one triangle per color space
+        assert_eq!(svg.matches("<polygon").count(), 3);
+        assert!(svg.contains(">sRGB</text>"));
+        assert!(svg.contains(">Rec.2020</text>"));
+
+        // names are escaped in the legend
+        let mut svg = Vec::new();
+        write_svg(
+            &mut svg,
+            Diagram::CIE1976,
+            &CIE_1931_2_DEGREE,
+            &[("R&D <\"P3\">", &model_f64::DCI_P3)],
+        )
+        .unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.contains(">R&amp;D &lt;&quot;P3&quot;&gt;</text>"));
+        assert!(!svg.contains("R&D"));
+    }
+}
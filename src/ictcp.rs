@@ -0,0 +1,351 @@
+//! ICtCp color space as defined in ITU-R BT.2100, with the ΔE_ITP color
+//! difference from ITU-R BT.2124.
+//!
+//! ICtCp is defined on ITU-R BT.2020 primaries. The PQ variant operates on
+//! absolute, display-referred light, so RGB and XYZ inputs are expected in
+//! cd/m². The HLG variant operates on relative, scene-referred light with
+//! nominal peak at 1.0.
+#![allow(non_snake_case)]
+use super::color_space_rgb::model_f64::ITUR_BT2020;
+use super::color_space_rgb::{
+    decode, encode, hlg_inverse_ootf, hlg_ootf, hlg_system_gamma,
+};
+use super::math::*;
+use super::rgb::*;
+use super::xyz::*;
+
+use numeric_literals::replace_float_literals;
+
+/// ICtCp colour value
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct ICtCp<T>
+where
+    T: Real,
+{
+    pub I: T,
+    pub Ct: T,
+    pub Cp: T,
+}
+
+/// Short constructor for an ICtCp
+pub fn ictcp<T>(I: T, Ct: T, Cp: T) -> ICtCp<T>
+where
+    T: Real,
+{
+    ICtCp { I, Ct, Cp }
+}
+
+#[replace_float_literals(T::from(literal).unwrap())]
+fn rgb_to_lms<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    #[rustfmt::skip]
+    let m = Matrix33::<T>::new([
+        1688.0, 2146.0,  262.0,
+         683.0, 2951.0,  462.0,
+          99.0,  309.0, 3688.0,
+    ]) / 4096.0;
+    m
+}
+
+#[replace_float_literals(T::from(literal).unwrap())]
+fn lms_to_ictcp_pq<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    #[rustfmt::skip]
+    let m = Matrix33::<T>::new([
+         2048.0,   2048.0,    0.0,
+         6610.0, -13613.0, 7003.0,
+        17933.0, -17390.0, -543.0,
+    ]) / 4096.0;
+    m
+}
+
+#[replace_float_literals(T::from(literal).unwrap())]
+fn lms_to_ictcp_hlg<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    #[rustfmt::skip]
+    let m = Matrix33::<T>::new([
+        2048.0,  2048.0,    0.0,
+        3625.0, -7465.0, 3840.0,
+        9500.0, -9212.0, -288.0,
+    ]) / 4096.0;
+    m
+}
+
+/// Inverse of [rgb_to_lms]
+#[replace_float_literals(T::from(literal).unwrap())]
+fn lms_to_rgb<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    #[rustfmt::skip]
+    let m = Matrix33::<T>::new([
+           3.4366066943330784,    -2.50645211865627, 0.06984542432319148,
+          -0.7913295555989287,   1.9836004517922907,  -0.192270896193362,
+        -0.025949899690592672, -0.09891371471172644,  1.1248636144023192,
+    ]);
+    m
+}
+
+/// Inverse of [lms_to_ictcp_pq]
+#[replace_float_literals(T::from(literal).unwrap())]
+fn ictcp_pq_to_lms<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    #[rustfmt::skip]
+    let m = Matrix33::<T>::new([
+        1.0,  0.008609037037932756,  0.11102962500302596,
+        1.0, -0.008609037037932756, -0.11102962500302596,
+        1.0,    0.5600313357106791, -0.32062717498731885,
+    ]);
+    m
+}
+
+/// Inverse of [lms_to_ictcp_hlg]
+#[replace_float_literals(T::from(literal).unwrap())]
+fn ictcp_hlg_to_lms<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    #[rustfmt::skip]
+    let m = Matrix33::<T>::new([
+        1.0,  0.015718580108730413,  0.2095810681164055,
+        1.0, -0.015718580108730413, -0.2095810681164055,
+        1.0,    1.0212710798422342, -0.6052744909924315,
+    ]);
+    m
+}
+
+/// SMPTE ST 2084 inverse EOTF, mapping absolute luminance in cd/m² to a
+/// signal value
+#[replace_float_literals(T::from(literal).unwrap())]
+fn pq_inverse_eotf<T>(L: T) -> T
+where
+    T: Real,
+{
+    encode::pq_t(L / 10000.0)
+}
+
+/// SMPTE ST 2084 EOTF, mapping a signal value to absolute luminance in cd/m²
+#[replace_float_literals(T::from(literal).unwrap())]
+fn pq_eotf<T>(E: T) -> T
+where
+    T: Real,
+{
+    decode::pq_t(E) * 10000.0
+}
+
+/// Convert linear ITU-R BT.2020 RGB in cd/m² to ICtCp using the PQ
+/// non-linearity
+pub fn rgb_to_ictcp_pq<T>(rgb: RGBf<T>) -> ICtCp<T>
+where
+    T: Real,
+{
+    let lms = rgb_to_lms() * rgb;
+    let lms = rgbf(
+        pq_inverse_eotf(lms.r),
+        pq_inverse_eotf(lms.g),
+        pq_inverse_eotf(lms.b),
+    );
+    let c = lms_to_ictcp_pq() * lms;
+    ictcp(c.r, c.g, c.b)
+}
+
+/// Convert ICtCp using the PQ non-linearity to linear ITU-R BT.2020 RGB in
+/// cd/m²
+pub fn ictcp_pq_to_rgb<T>(c: ICtCp<T>) -> RGBf<T>
+where
+    T: Real,
+{
+    let lms = ictcp_pq_to_lms() * rgbf(c.I, c.Ct, c.Cp);
+    let lms = rgbf(pq_eotf(lms.r), pq_eotf(lms.g), pq_eotf(lms.b));
+    lms_to_rgb() * lms
+}
+
+/// Convert scene-linear ITU-R BT.2020 RGB to ICtCp using the HLG
+/// non-linearity
+pub fn rgb_to_ictcp_hlg<T>(rgb: RGBf<T>) -> ICtCp<T>
+where
+    T: Real,
+{
+    let lms = rgb_to_lms() * rgb;
+    let lms = rgbf(
+        encode::hlg_t(lms.r),
+        encode::hlg_t(lms.g),
+        encode::hlg_t(lms.b),
+    );
+    let c = lms_to_ictcp_hlg() * lms;
+    ictcp(c.r, c.g, c.b)
+}
+
+/// Convert ICtCp using the HLG non-linearity to scene-linear ITU-R BT.2020
+/// RGB
+pub fn ictcp_hlg_to_rgb<T>(c: ICtCp<T>) -> RGBf<T>
+where
+    T: Real,
+{
+    let lms = ictcp_hlg_to_lms() * rgbf(c.I, c.Ct, c.Cp);
+    let lms = rgbf(
+        decode::hlg_t(lms.r),
+        decode::hlg_t(lms.g),
+        decode::hlg_t(lms.b),
+    );
+    lms_to_rgb() * lms
+}
+
+/// Convert XYZ relative to D65, in cd/m², to ICtCp using the PQ
+/// non-linearity
+pub fn xyz_to_ictcp_pq<T, X: Into<XYZ<T>>>(xyz: X) -> ICtCp<T>
+where
+    T: Real,
+{
+    let xyz: XYZ<T> = xyz.into();
+    let rgb = matrix_from_f64::<T>(&ITUR_BT2020.xf_xyz_to_rgb) * xyz;
+    rgb_to_ictcp_pq(rgbf(rgb.x, rgb.y, rgb.z))
+}
+
+/// Convert ICtCp using the PQ non-linearity to XYZ relative to D65, in
+/// cd/m²
+pub fn ictcp_pq_to_xyz<T>(c: ICtCp<T>) -> XYZ<T>
+where
+    T: Real,
+{
+    let rgb = ictcp_pq_to_rgb(c);
+    matrix_from_f64::<T>(&ITUR_BT2020.xf_rgb_to_xyz)
+        * XYZ::new(rgb.r, rgb.g, rgb.b)
+}
+
+/// Convert XYZ relative to D65, in cd/m² as shown on an HLG display with
+/// nominal peak luminance `l_w`, to ICtCp using the HLG non-linearity. The
+/// display light is first mapped back to scene light with the inverse HLG
+/// OOTF for the system gamma of that display.
+pub fn xyz_to_ictcp_hlg<T, X: Into<XYZ<T>>>(xyz: X, l_w: T) -> ICtCp<T>
+where
+    T: Real,
+{
+    let xyz: XYZ<T> = xyz.into();
+    let rgb = matrix_from_f64::<T>(&ITUR_BT2020.xf_xyz_to_rgb) * xyz;
+    let rgb = rgbf(rgb.x / l_w, rgb.y / l_w, rgb.z / l_w);
+    rgb_to_ictcp_hlg(hlg_inverse_ootf(rgb, hlg_system_gamma(l_w)))
+}
+
+/// Convert ICtCp using the HLG non-linearity to XYZ relative to D65, in
+/// cd/m² as shown on an HLG display with nominal peak luminance `l_w`
+pub fn ictcp_hlg_to_xyz<T>(c: ICtCp<T>, l_w: T) -> XYZ<T>
+where
+    T: Real,
+{
+    let rgb = hlg_ootf(ictcp_hlg_to_rgb(c), hlg_system_gamma(l_w)) * l_w;
+    matrix_from_f64::<T>(&ITUR_BT2020.xf_rgb_to_xyz)
+        * XYZ::new(rgb.r, rgb.g, rgb.b)
+}
+
+/// Compute the difference between two ICtCp colors according to the ΔE_ITP
+/// formula in ITU-R BT.2124. A difference of 1 is approximately one
+/// just-noticeable difference.
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn delta_E_ITP<T>(c1: ICtCp<T>, c2: ICtCp<T>) -> T
+where
+    T: Real,
+{
+    720.0
+        * (sqr(c1.I - c2.I) + sqr(0.5 * (c1.Ct - c2.Ct)) + sqr(c1.Cp - c2.Cp))
+            .sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverse_matrices() {
+        let i = M3f64::make_identity();
+        for m in [
+            rgb_to_lms::<f64>() * lms_to_rgb(),
+            lms_to_ictcp_pq::<f64>() * ictcp_pq_to_lms(),
+            lms_to_ictcp_hlg::<f64>() * ictcp_hlg_to_lms(),
+        ]
+        .iter()
+        {
+            for (a, b) in m.x.iter().zip(i.x.iter()) {
+                assert!((a - b).abs() < 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn ictcp_pq() {
+        // reference values from the colour-science library
+        let rgb = rgbf(0.45620519, 0.03081071, 0.04091952);
+        let c = rgb_to_ictcp_pq(rgb);
+        assert!((c.I - 0.07351364).abs() < 1e-8);
+        assert!((c.Ct - 0.00475253).abs() < 1e-8);
+        assert!((c.Cp - 0.09351596).abs() < 1e-8);
+
+        let rgb_2 = ictcp_pq_to_rgb(c);
+        assert!((rgb.r - rgb_2.r).abs() < 1e-10);
+        assert!((rgb.g - rgb_2.g).abs() < 1e-10);
+        assert!((rgb.b - rgb_2.b).abs() < 1e-10);
+
+        // PQ-encoded D65 white has no chroma
+        let w: ICtCp<f64> =
+            xyz_to_ictcp_pq(XYZ::from(crate::illuminant::xy::D65));
+        assert!(w.Ct.abs() < 1e-10);
+        assert!(w.Cp.abs() < 1e-10);
+
+        for xyz in crate::colorchecker::XYZ_D65.values() {
+            let xyz = *xyz * 10.0;
+            let xyz_2 = ictcp_pq_to_xyz(xyz_to_ictcp_pq::<f64, _>(xyz));
+            assert!((xyz.x - xyz_2.x).abs() < 1e-9);
+            assert!((xyz.y - xyz_2.y).abs() < 1e-9);
+            assert!((xyz.z - xyz_2.z).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn ictcp_hlg() {
+        let c = rgb_to_ictcp_hlg(rgbf(1.0f64, 1.0, 1.0));
+        assert!((c.I - 1.0).abs() < 1e-4);
+        assert!(c.Ct.abs() < 1e-10);
+        assert!(c.Cp.abs() < 1e-10);
+
+        for rgb in crate::colorchecker::SRGB_LINEAR.values() {
+            let rgb_2 = ictcp_hlg_to_rgb(rgb_to_ictcp_hlg(*rgb));
+            assert!((rgb.r - rgb_2.r).abs() < 1e-10);
+            assert!((rgb.g - rgb_2.g).abs() < 1e-10);
+            assert!((rgb.b - rgb_2.b).abs() < 1e-10);
+        }
+
+        // HLG reference white, 203 cd/m² on a 1000 cd/m² display, is a 75%
+        // signal (ITU-R BT.2408)
+        let w = XYZ::from(crate::illuminant::xy::D65) * 2.03;
+        let c = xyz_to_ictcp_hlg(w, 1000.0);
+        assert!((c.I - 0.75).abs() < 1e-3);
+        assert!(c.Ct.abs() < 1e-10);
+        assert!(c.Cp.abs() < 1e-10);
+
+        for xyz in crate::colorchecker::XYZ_D65.values() {
+            let xyz = *xyz * 10.0;
+            let xyz_2 = ictcp_hlg_to_xyz(xyz_to_ictcp_hlg(xyz, 1000.0), 1000.0);
+            assert!((xyz.x - xyz_2.x).abs() < 1e-9);
+            assert!((xyz.y - xyz_2.y).abs() < 1e-9);
+            assert!((xyz.z - xyz_2.z).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn delta_e_itp() {
+        // reference value from the colour-science library
+        let c1 = ictcp(0.4885468072, -0.04739350675, 0.07475401302);
+        let c2 = ictcp(0.4899203231, -0.04567508203, 0.07361341775);
+        assert!((delta_E_ITP(c1, c2) - 1.42657).abs() < 1e-5);
+        assert_eq!(delta_E_ITP(c1, c1), 0.0);
+    }
+}
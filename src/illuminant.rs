@@ -1,3 +1,14 @@
+//! Chromaticities and spectral power distributions of standard illuminants.
+//!
+//! [xy] holds chromaticities for the CIE 1931 2° observer, with `_10`
+//! variants for the CIE 1964 10° observer, and [spd] holds relative spectral
+//! power distributions. Tabulated data is provided for the CIE daylight
+//! illuminants D50, D55, D60 and D65, illuminant A and the fluorescent
+//! illuminants F1–F9, F11 and F12. Other daylight and Planckian sources can
+//! be computed with [spd::daylight] and [spd::blackbody].
+//!
+//! F10 and the CIE 15:2018 FL3.x, HP and LED illuminants are not included
+//! yet.
 pub mod xy {
     use crate::*;
 
@@ -31,9 +42,221 @@ pub mod xy {
         y: 0.33767,
         Y: 1.0,
     };
+    pub const A: XYY<f64> = XYY {
+        x: 0.44757,
+        y: 0.40745,
+        Y: 1.0,
+    };
+    pub const F1: XYY<f64> = XYY {
+        x: 0.31310,
+        y: 0.33727,
+        Y: 1.0,
+    };
+    pub const F2: XYY<f64> = XYY {
+        x: 0.37208,
+        y: 0.37529,
+        Y: 1.0,
+    };
+    pub const F3: XYY<f64> = XYY {
+        x: 0.40910,
+        y: 0.39430,
+        Y: 1.0,
+    };
+    pub const F4: XYY<f64> = XYY {
+        x: 0.44018,
+        y: 0.40329,
+        Y: 1.0,
+    };
+    pub const F5: XYY<f64> = XYY {
+        x: 0.31379,
+        y: 0.34531,
+        Y: 1.0,
+    };
+    pub const F6: XYY<f64> = XYY {
+        x: 0.37790,
+        y: 0.38835,
+        Y: 1.0,
+    };
+    pub const F7: XYY<f64> = XYY {
+        x: 0.31292,
+        y: 0.32933,
+        Y: 1.0,
+    };
+    pub const F8: XYY<f64> = XYY {
+        x: 0.34588,
+        y: 0.35875,
+        Y: 1.0,
+    };
+    pub const F9: XYY<f64> = XYY {
+        x: 0.37417,
+        y: 0.37281,
+        Y: 1.0,
+    };
+    pub const F11: XYY<f64> = XYY {
+        x: 0.38052,
+        y: 0.37713,
+        Y: 1.0,
+    };
+    pub const F12: XYY<f64> = XYY {
+        x: 0.43695,
+        y: 0.40441,
+        Y: 1.0,
+    };
+
+    // Chromaticities for the CIE 1964 10-degree standard observer
+    pub const D50_10: XYY<f64> = XYY {
+        x: 0.34773,
+        y: 0.35952,
+        Y: 1.0,
+    };
+    pub const D55_10: XYY<f64> = XYY {
+        x: 0.33411,
+        y: 0.34877,
+        Y: 1.0,
+    };
+    pub const D65_10: XYY<f64> = XYY {
+        x: 0.31382,
+        y: 0.33100,
+        Y: 1.0,
+    };
+    pub const A_10: XYY<f64> = XYY {
+        x: 0.45117,
+        y: 0.40594,
+        Y: 1.0,
+    };
+    pub const F1_10: XYY<f64> = XYY {
+        x: 0.31811,
+        y: 0.33559,
+        Y: 1.0,
+    };
+    pub const F2_10: XYY<f64> = XYY {
+        x: 0.37925,
+        y: 0.36733,
+        Y: 1.0,
+    };
+    pub const F3_10: XYY<f64> = XYY {
+        x: 0.41761,
+        y: 0.38324,
+        Y: 1.0,
+    };
+    pub const F4_10: XYY<f64> = XYY {
+        x: 0.44920,
+        y: 0.39074,
+        Y: 1.0,
+    };
+    pub const F5_10: XYY<f64> = XYY {
+        x: 0.31975,
+        y: 0.34246,
+        Y: 1.0,
+    };
+    pub const F6_10: XYY<f64> = XYY {
+        x: 0.38660,
+        y: 0.37847,
+        Y: 1.0,
+    };
+    pub const F7_10: XYY<f64> = XYY {
+        x: 0.31569,
+        y: 0.32960,
+        Y: 1.0,
+    };
+    pub const F8_10: XYY<f64> = XYY {
+        x: 0.34902,
+        y: 0.35939,
+        Y: 1.0,
+    };
+    pub const F9_10: XYY<f64> = XYY {
+        x: 0.37829,
+        y: 0.37045,
+        Y: 1.0,
+    };
+    pub const F11_10: XYY<f64> = XYY {
+        x: 0.38541,
+        y: 0.37123,
+        Y: 1.0,
+    };
+    pub const F12_10: XYY<f64> = XYY {
+        x: 0.44256,
+        y: 0.39717,
+        Y: 1.0,
+    };
+
+    /// Returns the chromaticity of the CIE daylight illuminant with the given
+    /// correlated colour temperature, `cct`, in Kelvin, as defined in
+    /// CIE 15:2004.
+    ///
+    /// Note that the nominal temperatures of the CIE D illuminants pre-date
+    /// the 1968 revision of the second radiation constant, so e.g. D65 is
+    /// obtained with `daylight(6500.0 * 1.4388 / 1.4380)`.
+    /// # Panics
+    /// Panics if `cct` is outside the range 4000K to 25000K
+    pub fn daylight(cct: f64) -> XYY<f64> {
+        if !(4000.0..=25000.0).contains(&cct) {
+            panic!(
+                "CIE daylight is only defined between 4000K and 25000K, got {}K",
+                cct
+            );
+        }
 
+        let t = cct;
+        let x = if t <= 7000.0 {
+            -4.6070e9 / (t * t * t)
+                + 2.9678e6 / (t * t)
+                + 0.09911e3 / t
+                + 0.244063
+        } else {
+            -2.0064e9 / (t * t * t)
+                + 1.9018e6 / (t * t)
+                + 0.24748e3 / t
+                + 0.237040
+        };
+        let y = -3.0 * x * x + 2.870 * x - 0.275;
+
+        xy(x, y)
+    }
+
+    /// Returns the chromaticity of a Planckian (blackbody) radiator at the
+    /// given `temperature`, in Kelvin, using the cubic spline approximation
+    /// of the Planckian locus from Kim et al. (2002). This is much faster
+    /// than integrating the output of [spd::blackbody](super::spd::blackbody)
+    /// and accurate to within 5e-4 in xy.
+    /// # Panics
+    /// Panics if `temperature` is outside the range 1667K to 25000K
+    pub fn blackbody(temperature: f64) -> XYY<f64> {
+        if !(1667.0..=25000.0).contains(&temperature) {
+            panic!(
+                "Planckian locus approximation is only defined between 1667K and 25000K, got {}K",
+                temperature
+            );
+        }
+
+        let t = temperature;
+        let x = if t <= 4000.0 {
+            -0.2661239e9 / (t * t * t) - 0.2343589e6 / (t * t)
+                + 0.8776956e3 / t
+                + 0.179910
+        } else {
+            -3.0258469e9 / (t * t * t)
+                + 2.1070379e6 / (t * t)
+                + 0.2226347e3 / t
+                + 0.240390
+        };
+
+        let y = if t <= 2222.0 {
+            -1.1063814 * x * x * x - 1.34811020 * x * x + 2.18555832 * x
+                - 0.20219683
+        } else if t <= 4000.0 {
+            -0.9549476 * x * x * x - 1.37418593 * x * x + 2.09137015 * x
+                - 0.16748867
+        } else {
+            3.0817580 * x * x * x - 5.87338670 * x * x + 3.75112997 * x
+                - 0.37001483
+        };
+
+        xy(x, y)
+    }
 }
 
+#[allow(clippy::approx_constant)]
 pub mod spd {
     use crate::*;
     use lazy_static::lazy_static;
@@ -445,5 +668,1430 @@ pub mod spd {
         775.0 => 65.094100,
         780.0 => 63.382800
         );
+
+        /// CIE standard illuminant A, representing a tungsten-filament
+        /// lamp. Computed from Planck's law for a blackbody at 2856K as
+        /// specified in CIE 15:2004
+        pub static ref A: VSPD = vspd!(
+        300.0 => 0.930483,
+        305.0 => 1.128215,
+        310.0 => 1.357693,
+        315.0 => 1.622193,
+        320.0 => 1.925083,
+        325.0 => 2.269795,
+        330.0 => 2.659806,
+        335.0 => 3.098607,
+        340.0 => 3.589682,
+        345.0 => 4.136478,
+        350.0 => 4.742384,
+        355.0 => 5.410699,
+        360.0 => 6.144618,
+        365.0 => 6.947199,
+        370.0 => 7.821349,
+        375.0 => 8.769802,
+        380.0 => 9.795100,
+        385.0 => 10.899576,
+        390.0 => 12.085345,
+        395.0 => 13.354287,
+        400.0 => 14.708038,
+        405.0 => 16.147984,
+        410.0 => 17.675252,
+        415.0 => 19.290709,
+        420.0 => 20.994957,
+        425.0 => 22.788336,
+        430.0 => 24.670923,
+        435.0 => 26.642533,
+        440.0 => 28.702730,
+        445.0 => 30.850827,
+        450.0 => 33.085893,
+        455.0 => 35.406766,
+        460.0 => 37.812057,
+        465.0 => 40.300163,
+        470.0 => 42.869276,
+        475.0 => 45.517397,
+        480.0 => 48.242343,
+        485.0 => 51.041764,
+        490.0 => 53.913153,
+        495.0 => 56.853859,
+        500.0 => 59.861099,
+        505.0 => 62.931972,
+        510.0 => 66.063473,
+        515.0 => 69.252500,
+        520.0 => 72.495872,
+        525.0 => 75.790339,
+        530.0 => 79.132595,
+        535.0 => 82.519284,
+        540.0 => 85.947018,
+        545.0 => 89.412386,
+        550.0 => 92.911959,
+        555.0 => 96.442306,
+        560.0 => 100.000000,
+        565.0 => 103.581627,
+        570.0 => 107.183795,
+        575.0 => 110.803141,
+        580.0 => 114.436338,
+        585.0 => 118.080103,
+        590.0 => 121.731201,
+        595.0 => 125.386453,
+        600.0 => 129.042739,
+        605.0 => 132.697006,
+        610.0 => 136.346267,
+        615.0 => 139.987613,
+        620.0 => 143.618206,
+        625.0 => 147.235291,
+        630.0 => 150.836194,
+        635.0 => 154.418327,
+        640.0 => 157.979186,
+        645.0 => 161.516355,
+        650.0 => 165.027510,
+        655.0 => 168.510413,
+        660.0 => 171.962920,
+        665.0 => 175.382976,
+        670.0 => 178.768618,
+        675.0 => 182.117973,
+        680.0 => 185.429259,
+        685.0 => 188.700786,
+        690.0 => 191.930950,
+        695.0 => 195.118238,
+        700.0 => 198.261223,
+        705.0 => 201.358565,
+        710.0 => 204.409009,
+        715.0 => 207.411381,
+        720.0 => 210.364592,
+        725.0 => 213.267630,
+        730.0 => 216.119565,
+        735.0 => 218.919540,
+        740.0 => 221.666775,
+        745.0 => 224.360562,
+        750.0 => 227.000263,
+        755.0 => 229.585310,
+        760.0 => 232.115201,
+        765.0 => 234.589499,
+        770.0 => 237.007829,
+        775.0 => 239.369877,
+        780.0 => 241.675388,
+        785.0 => 243.924163,
+        790.0 => 246.116055,
+        795.0 => 248.250973,
+        800.0 => 250.328873,
+        805.0 => 252.349762,
+        810.0 => 254.313690,
+        815.0 => 256.220755,
+        820.0 => 258.071092,
+        825.0 => 259.864882,
+        830.0 => 261.602340
+        );
+
+        /// CIE illuminant F1, daylight fluorescent lamp
+        pub static ref F1: VSPD = vspd!(
+        380.0 => 1.87,
+        385.0 => 2.36,
+        390.0 => 2.94,
+        395.0 => 3.47,
+        400.0 => 5.17,
+        405.0 => 19.49,
+        410.0 => 6.13,
+        415.0 => 6.24,
+        420.0 => 7.01,
+        425.0 => 7.79,
+        430.0 => 8.56,
+        435.0 => 43.67,
+        440.0 => 16.94,
+        445.0 => 10.72,
+        450.0 => 11.35,
+        455.0 => 11.89,
+        460.0 => 12.37,
+        465.0 => 12.75,
+        470.0 => 13.00,
+        475.0 => 13.15,
+        480.0 => 13.23,
+        485.0 => 13.17,
+        490.0 => 13.13,
+        495.0 => 12.85,
+        500.0 => 12.52,
+        505.0 => 12.20,
+        510.0 => 11.83,
+        515.0 => 11.50,
+        520.0 => 11.22,
+        525.0 => 11.05,
+        530.0 => 11.03,
+        535.0 => 11.18,
+        540.0 => 11.53,
+        545.0 => 27.74,
+        550.0 => 17.05,
+        555.0 => 13.55,
+        560.0 => 14.33,
+        565.0 => 15.01,
+        570.0 => 15.52,
+        575.0 => 18.29,
+        580.0 => 19.55,
+        585.0 => 15.48,
+        590.0 => 14.91,
+        595.0 => 14.15,
+        600.0 => 13.22,
+        605.0 => 12.19,
+        610.0 => 11.12,
+        615.0 => 10.03,
+        620.0 => 8.95,
+        625.0 => 7.96,
+        630.0 => 7.02,
+        635.0 => 6.20,
+        640.0 => 5.42,
+        645.0 => 4.73,
+        650.0 => 4.15,
+        655.0 => 3.64,
+        660.0 => 3.20,
+        665.0 => 2.81,
+        670.0 => 2.47,
+        675.0 => 2.18,
+        680.0 => 1.93,
+        685.0 => 1.72,
+        690.0 => 1.67,
+        695.0 => 1.43,
+        700.0 => 1.29,
+        705.0 => 1.19,
+        710.0 => 1.08,
+        715.0 => 0.96,
+        720.0 => 0.88,
+        725.0 => 0.81,
+        730.0 => 0.77,
+        735.0 => 0.75,
+        740.0 => 0.73,
+        745.0 => 0.68,
+        750.0 => 0.69,
+        755.0 => 0.64,
+        760.0 => 0.68,
+        765.0 => 0.69,
+        770.0 => 0.61,
+        775.0 => 0.52,
+        780.0 => 0.43
+        );
+
+        /// CIE illuminant F2, cool white fluorescent lamp
+        pub static ref F2: VSPD = vspd!(
+        380.0 => 1.18,
+        385.0 => 1.48,
+        390.0 => 1.84,
+        395.0 => 2.15,
+        400.0 => 3.44,
+        405.0 => 15.69,
+        410.0 => 3.85,
+        415.0 => 3.74,
+        420.0 => 4.19,
+        425.0 => 4.62,
+        430.0 => 5.06,
+        435.0 => 34.98,
+        440.0 => 11.81,
+        445.0 => 6.27,
+        450.0 => 6.63,
+        455.0 => 6.93,
+        460.0 => 7.19,
+        465.0 => 7.40,
+        470.0 => 7.54,
+        475.0 => 7.62,
+        480.0 => 7.65,
+        485.0 => 7.62,
+        490.0 => 7.62,
+        495.0 => 7.45,
+        500.0 => 7.28,
+        505.0 => 7.15,
+        510.0 => 7.05,
+        515.0 => 7.04,
+        520.0 => 7.16,
+        525.0 => 7.47,
+        530.0 => 8.04,
+        535.0 => 8.88,
+        540.0 => 10.01,
+        545.0 => 24.88,
+        550.0 => 16.64,
+        555.0 => 14.59,
+        560.0 => 16.16,
+        565.0 => 17.56,
+        570.0 => 18.62,
+        575.0 => 21.47,
+        580.0 => 22.79,
+        585.0 => 19.29,
+        590.0 => 18.66,
+        595.0 => 17.73,
+        600.0 => 16.54,
+        605.0 => 15.21,
+        610.0 => 13.80,
+        615.0 => 12.36,
+        620.0 => 10.95,
+        625.0 => 9.65,
+        630.0 => 8.40,
+        635.0 => 7.32,
+        640.0 => 6.31,
+        645.0 => 5.43,
+        650.0 => 4.68,
+        655.0 => 4.02,
+        660.0 => 3.45,
+        665.0 => 2.96,
+        670.0 => 2.55,
+        675.0 => 2.19,
+        680.0 => 1.89,
+        685.0 => 1.64,
+        690.0 => 1.53,
+        695.0 => 1.27,
+        700.0 => 1.10,
+        705.0 => 0.99,
+        710.0 => 0.88,
+        715.0 => 0.76,
+        720.0 => 0.68,
+        725.0 => 0.61,
+        730.0 => 0.56,
+        735.0 => 0.54,
+        740.0 => 0.51,
+        745.0 => 0.47,
+        750.0 => 0.47,
+        755.0 => 0.43,
+        760.0 => 0.46,
+        765.0 => 0.47,
+        770.0 => 0.40,
+        775.0 => 0.33,
+        780.0 => 0.27
+        );
+
+        /// CIE illuminant F3, white fluorescent lamp
+        pub static ref F3: VSPD = vspd!(
+        380.0 => 0.82,
+        385.0 => 1.02,
+        390.0 => 1.26,
+        395.0 => 1.44,
+        400.0 => 2.57,
+        405.0 => 14.36,
+        410.0 => 2.70,
+        415.0 => 2.45,
+        420.0 => 2.73,
+        425.0 => 3.00,
+        430.0 => 3.28,
+        435.0 => 31.85,
+        440.0 => 9.47,
+        445.0 => 4.02,
+        450.0 => 4.25,
+        455.0 => 4.44,
+        460.0 => 4.59,
+        465.0 => 4.72,
+        470.0 => 4.80,
+        475.0 => 4.86,
+        480.0 => 4.87,
+        485.0 => 4.85,
+        490.0 => 4.88,
+        495.0 => 4.77,
+        500.0 => 4.67,
+        505.0 => 4.62,
+        510.0 => 4.62,
+        515.0 => 4.73,
+        520.0 => 4.99,
+        525.0 => 5.48,
+        530.0 => 6.25,
+        535.0 => 7.34,
+        540.0 => 8.78,
+        545.0 => 23.82,
+        550.0 => 16.14,
+        555.0 => 14.59,
+        560.0 => 16.63,
+        565.0 => 18.49,
+        570.0 => 19.95,
+        575.0 => 23.11,
+        580.0 => 24.69,
+        585.0 => 21.41,
+        590.0 => 20.85,
+        595.0 => 19.93,
+        600.0 => 18.67,
+        605.0 => 17.22,
+        610.0 => 15.65,
+        615.0 => 14.04,
+        620.0 => 12.45,
+        625.0 => 10.95,
+        630.0 => 9.51,
+        635.0 => 8.27,
+        640.0 => 7.11,
+        645.0 => 6.09,
+        650.0 => 5.22,
+        655.0 => 4.45,
+        660.0 => 3.80,
+        665.0 => 3.23,
+        670.0 => 2.75,
+        675.0 => 2.33,
+        680.0 => 1.99,
+        685.0 => 1.70,
+        690.0 => 1.55,
+        695.0 => 1.27,
+        700.0 => 1.09,
+        705.0 => 0.96,
+        710.0 => 0.83,
+        715.0 => 0.71,
+        720.0 => 0.62,
+        725.0 => 0.54,
+        730.0 => 0.49,
+        735.0 => 0.46,
+        740.0 => 0.43,
+        745.0 => 0.39,
+        750.0 => 0.39,
+        755.0 => 0.35,
+        760.0 => 0.38,
+        765.0 => 0.39,
+        770.0 => 0.33,
+        775.0 => 0.28,
+        780.0 => 0.21
+        );
+
+        /// CIE illuminant F4, warm white fluorescent lamp
+        pub static ref F4: VSPD = vspd!(
+        380.0 => 0.57,
+        385.0 => 0.70,
+        390.0 => 0.87,
+        395.0 => 0.98,
+        400.0 => 2.01,
+        405.0 => 13.75,
+        410.0 => 1.95,
+        415.0 => 1.59,
+        420.0 => 1.76,
+        425.0 => 1.93,
+        430.0 => 2.10,
+        435.0 => 30.28,
+        440.0 => 8.03,
+        445.0 => 2.55,
+        450.0 => 2.70,
+        455.0 => 2.82,
+        460.0 => 2.91,
+        465.0 => 2.99,
+        470.0 => 3.04,
+        475.0 => 3.08,
+        480.0 => 3.09,
+        485.0 => 3.09,
+        490.0 => 3.14,
+        495.0 => 3.06,
+        500.0 => 3.00,
+        505.0 => 2.98,
+        510.0 => 3.01,
+        515.0 => 3.14,
+        520.0 => 3.41,
+        525.0 => 3.90,
+        530.0 => 4.69,
+        535.0 => 5.81,
+        540.0 => 7.32,
+        545.0 => 22.59,
+        550.0 => 15.11,
+        555.0 => 13.88,
+        560.0 => 16.33,
+        565.0 => 18.68,
+        570.0 => 20.64,
+        575.0 => 24.28,
+        580.0 => 26.26,
+        585.0 => 23.28,
+        590.0 => 22.94,
+        595.0 => 22.14,
+        600.0 => 20.91,
+        605.0 => 19.43,
+        610.0 => 17.74,
+        615.0 => 16.00,
+        620.0 => 14.42,
+        625.0 => 12.56,
+        630.0 => 10.93,
+        635.0 => 9.52,
+        640.0 => 8.18,
+        645.0 => 7.01,
+        650.0 => 6.00,
+        655.0 => 5.11,
+        660.0 => 4.36,
+        665.0 => 3.69,
+        670.0 => 3.13,
+        675.0 => 2.64,
+        680.0 => 2.24,
+        685.0 => 1.91,
+        690.0 => 1.70,
+        695.0 => 1.39,
+        700.0 => 1.18,
+        705.0 => 1.03,
+        710.0 => 0.88,
+        715.0 => 0.74,
+        720.0 => 0.64,
+        725.0 => 0.54,
+        730.0 => 0.49,
+        735.0 => 0.46,
+        740.0 => 0.42,
+        745.0 => 0.37,
+        750.0 => 0.37,
+        755.0 => 0.33,
+        760.0 => 0.35,
+        765.0 => 0.36,
+        770.0 => 0.31,
+        775.0 => 0.26,
+        780.0 => 0.19
+        );
+
+        /// CIE illuminant F5, daylight fluorescent lamp
+        pub static ref F5: VSPD = vspd!(
+        380.0 => 1.87,
+        385.0 => 2.35,
+        390.0 => 2.92,
+        395.0 => 3.45,
+        400.0 => 5.10,
+        405.0 => 18.91,
+        410.0 => 6.00,
+        415.0 => 6.11,
+        420.0 => 6.85,
+        425.0 => 7.58,
+        430.0 => 8.31,
+        435.0 => 40.76,
+        440.0 => 16.06,
+        445.0 => 10.32,
+        450.0 => 10.91,
+        455.0 => 11.40,
+        460.0 => 11.83,
+        465.0 => 12.17,
+        470.0 => 12.40,
+        475.0 => 12.54,
+        480.0 => 12.58,
+        485.0 => 12.52,
+        490.0 => 12.47,
+        495.0 => 12.20,
+        500.0 => 11.89,
+        505.0 => 11.61,
+        510.0 => 11.33,
+        515.0 => 11.10,
+        520.0 => 10.96,
+        525.0 => 10.97,
+        530.0 => 11.16,
+        535.0 => 11.54,
+        540.0 => 12.12,
+        545.0 => 27.78,
+        550.0 => 17.73,
+        555.0 => 14.47,
+        560.0 => 15.20,
+        565.0 => 15.77,
+        570.0 => 16.10,
+        575.0 => 18.54,
+        580.0 => 19.50,
+        585.0 => 15.39,
+        590.0 => 14.64,
+        595.0 => 13.72,
+        600.0 => 12.69,
+        605.0 => 11.57,
+        610.0 => 10.45,
+        615.0 => 9.35,
+        620.0 => 8.29,
+        625.0 => 7.32,
+        630.0 => 6.41,
+        635.0 => 5.63,
+        640.0 => 4.90,
+        645.0 => 4.26,
+        650.0 => 3.72,
+        655.0 => 3.25,
+        660.0 => 2.83,
+        665.0 => 2.49,
+        670.0 => 2.19,
+        675.0 => 1.93,
+        680.0 => 1.71,
+        685.0 => 1.52,
+        690.0 => 1.48,
+        695.0 => 1.26,
+        700.0 => 1.13,
+        705.0 => 1.05,
+        710.0 => 0.96,
+        715.0 => 0.85,
+        720.0 => 0.78,
+        725.0 => 0.72,
+        730.0 => 0.68,
+        735.0 => 0.67,
+        740.0 => 0.65,
+        745.0 => 0.61,
+        750.0 => 0.62,
+        755.0 => 0.59,
+        760.0 => 0.62,
+        765.0 => 0.64,
+        770.0 => 0.55,
+        775.0 => 0.47,
+        780.0 => 0.40
+        );
+
+        /// CIE illuminant F6, lite white fluorescent lamp
+        pub static ref F6: VSPD = vspd!(
+        380.0 => 1.05,
+        385.0 => 1.31,
+        390.0 => 1.63,
+        395.0 => 1.90,
+        400.0 => 3.11,
+        405.0 => 14.80,
+        410.0 => 3.43,
+        415.0 => 3.30,
+        420.0 => 3.68,
+        425.0 => 4.07,
+        430.0 => 4.45,
+        435.0 => 32.61,
+        440.0 => 10.74,
+        445.0 => 5.48,
+        450.0 => 5.78,
+        455.0 => 6.03,
+        460.0 => 6.25,
+        465.0 => 6.41,
+        470.0 => 6.52,
+        475.0 => 6.58,
+        480.0 => 6.59,
+        485.0 => 6.56,
+        490.0 => 6.56,
+        495.0 => 6.42,
+        500.0 => 6.28,
+        505.0 => 6.20,
+        510.0 => 6.19,
+        515.0 => 6.30,
+        520.0 => 6.60,
+        525.0 => 7.12,
+        530.0 => 7.94,
+        535.0 => 9.07,
+        540.0 => 10.49,
+        545.0 => 25.22,
+        550.0 => 17.46,
+        555.0 => 15.63,
+        560.0 => 17.22,
+        565.0 => 18.53,
+        570.0 => 19.43,
+        575.0 => 21.97,
+        580.0 => 23.01,
+        585.0 => 19.41,
+        590.0 => 18.56,
+        595.0 => 17.42,
+        600.0 => 16.09,
+        605.0 => 14.64,
+        610.0 => 13.15,
+        615.0 => 11.68,
+        620.0 => 10.25,
+        625.0 => 8.95,
+        630.0 => 7.74,
+        635.0 => 6.69,
+        640.0 => 5.71,
+        645.0 => 4.87,
+        650.0 => 4.16,
+        655.0 => 3.55,
+        660.0 => 3.02,
+        665.0 => 2.57,
+        670.0 => 2.20,
+        675.0 => 1.87,
+        680.0 => 1.60,
+        685.0 => 1.37,
+        690.0 => 1.29,
+        695.0 => 1.08,
+        700.0 => 0.96,
+        705.0 => 0.88,
+        710.0 => 0.77,
+        715.0 => 0.67,
+        720.0 => 0.59,
+        725.0 => 0.52,
+        730.0 => 0.47,
+        735.0 => 0.44,
+        740.0 => 0.42,
+        745.0 => 0.37,
+        750.0 => 0.38,
+        755.0 => 0.35,
+        760.0 => 0.36,
+        765.0 => 0.36,
+        770.0 => 0.32,
+        775.0 => 0.26,
+        780.0 => 0.21
+        );
+
+        /// CIE illuminant F7, broadband D65 simulator fluorescent lamp
+        pub static ref F7: VSPD = vspd!(
+        380.0 => 2.56,
+        385.0 => 3.18,
+        390.0 => 3.84,
+        395.0 => 4.53,
+        400.0 => 6.15,
+        405.0 => 19.37,
+        410.0 => 7.37,
+        415.0 => 7.05,
+        420.0 => 7.71,
+        425.0 => 8.41,
+        430.0 => 9.15,
+        435.0 => 44.14,
+        440.0 => 17.52,
+        445.0 => 11.35,
+        450.0 => 12.00,
+        455.0 => 12.58,
+        460.0 => 13.08,
+        465.0 => 13.45,
+        470.0 => 13.71,
+        475.0 => 13.88,
+        480.0 => 13.95,
+        485.0 => 13.93,
+        490.0 => 13.82,
+        495.0 => 13.64,
+        500.0 => 13.43,
+        505.0 => 13.25,
+        510.0 => 13.08,
+        515.0 => 12.93,
+        520.0 => 12.78,
+        525.0 => 12.60,
+        530.0 => 12.44,
+        535.0 => 12.33,
+        540.0 => 12.26,
+        545.0 => 29.52,
+        550.0 => 17.05,
+        555.0 => 12.44,
+        560.0 => 12.58,
+        565.0 => 12.72,
+        570.0 => 12.83,
+        575.0 => 15.46,
+        580.0 => 16.75,
+        585.0 => 12.83,
+        590.0 => 12.67,
+        595.0 => 12.45,
+        600.0 => 12.19,
+        605.0 => 11.89,
+        610.0 => 11.60,
+        615.0 => 11.35,
+        620.0 => 11.12,
+        625.0 => 10.95,
+        630.0 => 10.76,
+        635.0 => 10.42,
+        640.0 => 10.11,
+        645.0 => 10.04,
+        650.0 => 10.02,
+        655.0 => 10.11,
+        660.0 => 9.87,
+        665.0 => 8.65,
+        670.0 => 7.27,
+        675.0 => 6.44,
+        680.0 => 5.83,
+        685.0 => 5.41,
+        690.0 => 5.04,
+        695.0 => 4.57,
+        700.0 => 4.12,
+        705.0 => 3.77,
+        710.0 => 3.46,
+        715.0 => 3.08,
+        720.0 => 2.73,
+        725.0 => 2.47,
+        730.0 => 2.25,
+        735.0 => 2.06,
+        740.0 => 1.90,
+        745.0 => 1.75,
+        750.0 => 1.62,
+        755.0 => 1.54,
+        760.0 => 1.45,
+        765.0 => 1.32,
+        770.0 => 1.17,
+        775.0 => 0.99,
+        780.0 => 0.81
+        );
+
+        /// CIE illuminant F8, broadband D50 simulator fluorescent lamp
+        pub static ref F8: VSPD = vspd!(
+        380.0 => 1.21,
+        385.0 => 1.50,
+        390.0 => 1.81,
+        395.0 => 2.13,
+        400.0 => 3.17,
+        405.0 => 13.08,
+        410.0 => 3.83,
+        415.0 => 3.45,
+        420.0 => 3.86,
+        425.0 => 4.42,
+        430.0 => 5.09,
+        435.0 => 34.10,
+        440.0 => 12.42,
+        445.0 => 7.68,
+        450.0 => 8.60,
+        455.0 => 9.46,
+        460.0 => 10.24,
+        465.0 => 10.84,
+        470.0 => 11.33,
+        475.0 => 11.71,
+        480.0 => 11.98,
+        485.0 => 12.17,
+        490.0 => 12.28,
+        495.0 => 12.32,
+        500.0 => 12.35,
+        505.0 => 12.44,
+        510.0 => 12.55,
+        515.0 => 12.68,
+        520.0 => 12.77,
+        525.0 => 12.72,
+        530.0 => 12.60,
+        535.0 => 12.43,
+        540.0 => 12.22,
+        545.0 => 28.96,
+        550.0 => 16.51,
+        555.0 => 11.79,
+        560.0 => 11.76,
+        565.0 => 11.77,
+        570.0 => 11.84,
+        575.0 => 14.61,
+        580.0 => 16.11,
+        585.0 => 12.34,
+        590.0 => 12.53,
+        595.0 => 12.72,
+        600.0 => 12.92,
+        605.0 => 13.12,
+        610.0 => 13.34,
+        615.0 => 13.61,
+        620.0 => 13.87,
+        625.0 => 14.07,
+        630.0 => 14.20,
+        635.0 => 14.16,
+        640.0 => 14.13,
+        645.0 => 14.34,
+        650.0 => 14.50,
+        655.0 => 14.46,
+        660.0 => 14.00,
+        665.0 => 12.58,
+        670.0 => 10.99,
+        675.0 => 9.98,
+        680.0 => 9.22,
+        685.0 => 8.62,
+        690.0 => 8.07,
+        695.0 => 7.39,
+        700.0 => 6.71,
+        705.0 => 6.16,
+        710.0 => 5.63,
+        715.0 => 5.03,
+        720.0 => 4.46,
+        725.0 => 4.02,
+        730.0 => 3.66,
+        735.0 => 3.36,
+        740.0 => 3.09,
+        745.0 => 2.85,
+        750.0 => 2.65,
+        755.0 => 2.51,
+        760.0 => 2.37,
+        765.0 => 2.15,
+        770.0 => 1.89,
+        775.0 => 1.61,
+        780.0 => 1.32
+        );
+
+        /// CIE illuminant F9, broadband cool white deluxe fluorescent lamp
+        pub static ref F9: VSPD = vspd!(
+        380.0 => 0.90,
+        385.0 => 1.12,
+        390.0 => 1.36,
+        395.0 => 1.60,
+        400.0 => 2.59,
+        405.0 => 12.80,
+        410.0 => 3.05,
+        415.0 => 2.56,
+        420.0 => 2.86,
+        425.0 => 3.30,
+        430.0 => 3.82,
+        435.0 => 32.62,
+        440.0 => 10.77,
+        445.0 => 5.84,
+        450.0 => 6.57,
+        455.0 => 7.25,
+        460.0 => 7.86,
+        465.0 => 8.35,
+        470.0 => 8.75,
+        475.0 => 9.06,
+        480.0 => 9.31,
+        485.0 => 9.48,
+        490.0 => 9.61,
+        495.0 => 9.68,
+        500.0 => 9.74,
+        505.0 => 9.88,
+        510.0 => 10.04,
+        515.0 => 10.26,
+        520.0 => 10.48,
+        525.0 => 10.63,
+        530.0 => 10.78,
+        535.0 => 10.96,
+        540.0 => 11.18,
+        545.0 => 27.71,
+        550.0 => 16.29,
+        555.0 => 12.28,
+        560.0 => 12.74,
+        565.0 => 13.21,
+        570.0 => 13.65,
+        575.0 => 16.57,
+        580.0 => 18.14,
+        585.0 => 14.55,
+        590.0 => 14.65,
+        595.0 => 14.66,
+        600.0 => 14.61,
+        605.0 => 14.50,
+        610.0 => 14.39,
+        615.0 => 14.40,
+        620.0 => 14.47,
+        625.0 => 14.62,
+        630.0 => 14.72,
+        635.0 => 14.55,
+        640.0 => 14.40,
+        645.0 => 14.58,
+        650.0 => 14.88,
+        655.0 => 15.51,
+        660.0 => 15.47,
+        665.0 => 13.20,
+        670.0 => 10.57,
+        675.0 => 9.18,
+        680.0 => 8.25,
+        685.0 => 7.57,
+        690.0 => 7.03,
+        695.0 => 6.35,
+        700.0 => 5.72,
+        705.0 => 5.25,
+        710.0 => 4.80,
+        715.0 => 4.29,
+        720.0 => 3.80,
+        725.0 => 3.43,
+        730.0 => 3.12,
+        735.0 => 2.86,
+        740.0 => 2.64,
+        745.0 => 2.43,
+        750.0 => 2.26,
+        755.0 => 2.14,
+        760.0 => 2.02,
+        765.0 => 1.83,
+        770.0 => 1.61,
+        775.0 => 1.38,
+        780.0 => 1.12
+        );
+
+        /// CIE illuminant F11, narrow tri-band 4000K fluorescent lamp
+        pub static ref F11: VSPD = vspd!(
+        380.0 => 0.91,
+        385.0 => 0.63,
+        390.0 => 0.46,
+        395.0 => 0.37,
+        400.0 => 1.29,
+        405.0 => 12.68,
+        410.0 => 1.59,
+        415.0 => 1.79,
+        420.0 => 2.46,
+        425.0 => 3.33,
+        430.0 => 4.49,
+        435.0 => 33.94,
+        440.0 => 12.13,
+        445.0 => 6.95,
+        450.0 => 7.19,
+        455.0 => 7.12,
+        460.0 => 6.72,
+        465.0 => 6.13,
+        470.0 => 5.46,
+        475.0 => 4.79,
+        480.0 => 5.66,
+        485.0 => 14.29,
+        490.0 => 14.96,
+        495.0 => 8.97,
+        500.0 => 4.72,
+        505.0 => 2.33,
+        510.0 => 1.47,
+        515.0 => 1.10,
+        520.0 => 0.89,
+        525.0 => 0.83,
+        530.0 => 1.18,
+        535.0 => 4.90,
+        540.0 => 39.59,
+        545.0 => 72.84,
+        550.0 => 32.61,
+        555.0 => 7.52,
+        560.0 => 2.83,
+        565.0 => 1.96,
+        570.0 => 1.67,
+        575.0 => 4.43,
+        580.0 => 11.28,
+        585.0 => 14.76,
+        590.0 => 12.73,
+        595.0 => 9.74,
+        600.0 => 7.33,
+        605.0 => 9.72,
+        610.0 => 55.27,
+        615.0 => 42.58,
+        620.0 => 13.18,
+        625.0 => 13.16,
+        630.0 => 12.26,
+        635.0 => 5.11,
+        640.0 => 2.07,
+        645.0 => 2.34,
+        650.0 => 3.58,
+        655.0 => 3.01,
+        660.0 => 2.48,
+        665.0 => 2.14,
+        670.0 => 1.54,
+        675.0 => 1.33,
+        680.0 => 1.46,
+        685.0 => 1.94,
+        690.0 => 2.00,
+        695.0 => 1.20,
+        700.0 => 1.35,
+        705.0 => 4.10,
+        710.0 => 5.58,
+        715.0 => 2.51,
+        720.0 => 0.57,
+        725.0 => 0.27,
+        730.0 => 0.23,
+        735.0 => 0.21,
+        740.0 => 0.24,
+        745.0 => 0.24,
+        750.0 => 0.20,
+        755.0 => 0.24,
+        760.0 => 0.32,
+        765.0 => 0.26,
+        770.0 => 0.16,
+        775.0 => 0.12,
+        780.0 => 0.09
+        );
+
+        /// CIE illuminant F12, narrow tri-band 3000K fluorescent lamp
+        pub static ref F12: VSPD = vspd!(
+        380.0 => 0.96,
+        385.0 => 0.64,
+        390.0 => 0.40,
+        395.0 => 0.33,
+        400.0 => 1.19,
+        405.0 => 12.48,
+        410.0 => 1.12,
+        415.0 => 0.94,
+        420.0 => 1.08,
+        425.0 => 1.37,
+        430.0 => 1.78,
+        435.0 => 29.05,
+        440.0 => 7.90,
+        445.0 => 2.65,
+        450.0 => 2.71,
+        455.0 => 2.65,
+        460.0 => 2.49,
+        465.0 => 2.33,
+        470.0 => 2.10,
+        475.0 => 1.91,
+        480.0 => 3.01,
+        485.0 => 10.83,
+        490.0 => 11.88,
+        495.0 => 6.88,
+        500.0 => 3.43,
+        505.0 => 1.49,
+        510.0 => 0.92,
+        515.0 => 0.71,
+        520.0 => 0.60,
+        525.0 => 0.63,
+        530.0 => 1.10,
+        535.0 => 4.56,
+        540.0 => 34.40,
+        545.0 => 65.40,
+        550.0 => 29.48,
+        555.0 => 7.16,
+        560.0 => 3.08,
+        565.0 => 2.47,
+        570.0 => 2.27,
+        575.0 => 5.09,
+        580.0 => 11.96,
+        585.0 => 15.32,
+        590.0 => 14.27,
+        595.0 => 11.86,
+        600.0 => 9.28,
+        605.0 => 12.31,
+        610.0 => 68.53,
+        615.0 => 53.02,
+        620.0 => 14.67,
+        625.0 => 14.38,
+        630.0 => 14.71,
+        635.0 => 6.46,
+        640.0 => 2.57,
+        645.0 => 2.75,
+        650.0 => 4.18,
+        655.0 => 3.44,
+        660.0 => 2.81,
+        665.0 => 2.42,
+        670.0 => 1.64,
+        675.0 => 1.36,
+        680.0 => 1.49,
+        685.0 => 2.14,
+        690.0 => 2.34,
+        695.0 => 1.42,
+        700.0 => 1.61,
+        705.0 => 5.04,
+        710.0 => 6.98,
+        715.0 => 3.19,
+        720.0 => 0.71,
+        725.0 => 0.30,
+        730.0 => 0.26,
+        735.0 => 0.23,
+        740.0 => 0.28,
+        745.0 => 0.28,
+        750.0 => 0.21,
+        755.0 => 0.17,
+        760.0 => 0.21,
+        765.0 => 0.19,
+        770.0 => 0.15,
+        775.0 => 0.10,
+        780.0 => 0.05
+        );
+
+        /// CIE daylight basis function S0 (mean), tabulated at 10nm
+        pub static ref DAYLIGHT_S0: VSPD = vspd!(
+        300.0 => 0.04,
+        310.0 => 6.00,
+        320.0 => 29.60,
+        330.0 => 55.30,
+        340.0 => 57.30,
+        350.0 => 61.80,
+        360.0 => 61.50,
+        370.0 => 68.80,
+        380.0 => 63.40,
+        390.0 => 65.80,
+        400.0 => 94.80,
+        410.0 => 104.80,
+        420.0 => 105.90,
+        430.0 => 96.80,
+        440.0 => 113.90,
+        450.0 => 125.60,
+        460.0 => 125.50,
+        470.0 => 121.30,
+        480.0 => 121.30,
+        490.0 => 113.50,
+        500.0 => 113.10,
+        510.0 => 110.80,
+        520.0 => 106.50,
+        530.0 => 108.80,
+        540.0 => 105.30,
+        550.0 => 104.40,
+        560.0 => 100.00,
+        570.0 => 96.00,
+        580.0 => 95.10,
+        590.0 => 89.10,
+        600.0 => 90.50,
+        610.0 => 90.30,
+        620.0 => 88.40,
+        630.0 => 84.00,
+        640.0 => 85.10,
+        650.0 => 81.90,
+        660.0 => 82.60,
+        670.0 => 84.90,
+        680.0 => 81.30,
+        690.0 => 71.90,
+        700.0 => 74.30,
+        710.0 => 76.40,
+        720.0 => 63.30,
+        730.0 => 71.70,
+        740.0 => 77.00,
+        750.0 => 65.20,
+        760.0 => 47.70,
+        770.0 => 68.60,
+        780.0 => 65.00,
+        790.0 => 66.00,
+        800.0 => 61.00,
+        810.0 => 53.30,
+        820.0 => 58.90,
+        830.0 => 61.90
+        );
+
+        /// CIE daylight basis function S1 (first characteristic vector), tabulated at 10nm
+        pub static ref DAYLIGHT_S1: VSPD = vspd!(
+        300.0 => 0.02,
+        310.0 => 4.50,
+        320.0 => 22.40,
+        330.0 => 42.00,
+        340.0 => 40.60,
+        350.0 => 41.60,
+        360.0 => 38.00,
+        370.0 => 42.40,
+        380.0 => 38.50,
+        390.0 => 35.00,
+        400.0 => 43.40,
+        410.0 => 46.30,
+        420.0 => 43.90,
+        430.0 => 37.10,
+        440.0 => 36.70,
+        450.0 => 35.90,
+        460.0 => 32.60,
+        470.0 => 27.90,
+        480.0 => 24.30,
+        490.0 => 20.10,
+        500.0 => 16.20,
+        510.0 => 13.20,
+        520.0 => 8.60,
+        530.0 => 6.10,
+        540.0 => 4.20,
+        550.0 => 1.90,
+        560.0 => 0.00,
+        570.0 => -1.60,
+        580.0 => -3.50,
+        590.0 => -3.50,
+        600.0 => -5.80,
+        610.0 => -7.20,
+        620.0 => -8.60,
+        630.0 => -9.50,
+        640.0 => -10.90,
+        650.0 => -10.70,
+        660.0 => -12.00,
+        670.0 => -14.00,
+        680.0 => -13.60,
+        690.0 => -12.00,
+        700.0 => -13.30,
+        710.0 => -12.90,
+        720.0 => -10.60,
+        730.0 => -11.60,
+        740.0 => -12.20,
+        750.0 => -10.20,
+        760.0 => -7.80,
+        770.0 => -11.20,
+        780.0 => -10.40,
+        790.0 => -10.60,
+        800.0 => -9.70,
+        810.0 => -8.30,
+        820.0 => -9.30,
+        830.0 => -9.80
+        );
+
+        /// CIE daylight basis function S2 (second characteristic vector), tabulated at 10nm
+        pub static ref DAYLIGHT_S2: VSPD = vspd!(
+        300.0 => 0.00,
+        310.0 => 2.00,
+        320.0 => 4.00,
+        330.0 => 8.50,
+        340.0 => 7.80,
+        350.0 => 6.70,
+        360.0 => 5.30,
+        370.0 => 6.10,
+        380.0 => 3.00,
+        390.0 => 1.20,
+        400.0 => -1.10,
+        410.0 => -0.50,
+        420.0 => -0.70,
+        430.0 => -1.20,
+        440.0 => -2.60,
+        450.0 => -2.90,
+        460.0 => -2.80,
+        470.0 => -2.60,
+        480.0 => -2.60,
+        490.0 => -1.80,
+        500.0 => -1.50,
+        510.0 => -1.30,
+        520.0 => -1.20,
+        530.0 => -1.00,
+        540.0 => -0.50,
+        550.0 => -0.30,
+        560.0 => 0.00,
+        570.0 => 0.20,
+        580.0 => 0.50,
+        590.0 => 2.10,
+        600.0 => 3.20,
+        610.0 => 4.10,
+        620.0 => 4.70,
+        630.0 => 5.10,
+        640.0 => 6.70,
+        650.0 => 7.30,
+        660.0 => 8.60,
+        670.0 => 9.80,
+        680.0 => 10.20,
+        690.0 => 8.30,
+        700.0 => 9.60,
+        710.0 => 8.50,
+        720.0 => 7.00,
+        730.0 => 7.60,
+        740.0 => 8.00,
+        750.0 => 6.70,
+        760.0 => 5.20,
+        770.0 => 7.40,
+        780.0 => 6.80,
+        790.0 => 7.00,
+        800.0 => 6.40,
+        810.0 => 5.50,
+        820.0 => 6.10,
+        830.0 => 6.50
+        );
+    }
+
+    /// Returns the spectral power distribution of the CIE daylight illuminant
+    /// with the given correlated colour temperature, `cct`, in Kelvin,
+    /// computed from the S0, S1 and S2 basis functions as described in
+    /// CIE 15:2004. The result covers 300nm to 830nm at 5nm, with the basis
+    /// functions linearly interpolated as the CIE recommends, and is
+    /// normalized to 100 at 560nm.
+    /// # Panics
+    /// Panics if `cct` is outside the range 4000K to 25000K
+    pub fn daylight(cct: f64) -> VSPD {
+        let c = super::xy::daylight(cct);
+        let m = 0.0241 + 0.2562 * c.x - 0.7341 * c.y;
+        let m1 = (-1.3515 - 1.7703 * c.x + 5.9114 * c.y) / m;
+        let m2 = (0.0300 - 31.4424 * c.x + 30.0717 * c.y) / m;
+
+        let s0 = InterpolatorLinear::new(&DAYLIGHT_S0);
+        let s1 = InterpolatorLinear::new(&DAYLIGHT_S1);
+        let s2 = InterpolatorLinear::new(&DAYLIGHT_S2);
+
+        SpdShape::new(300.0, 830.0, 5.0)
+            .iter()
+            .map(|nm| {
+                (
+                    nm,
+                    s0.evaluate(nm)
+                        + m1 * s1.evaluate(nm)
+                        + m2 * s2.evaluate(nm),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the spectral radiance of a Planckian (blackbody) radiator at
+    /// the given `temperature`, in Kelvin, and wavelength `nm`, in
+    /// nanometres. The result is in W·sr⁻¹·m⁻²·nm⁻¹
+    pub fn planck(nm: f64, temperature: f64) -> f64 {
+        const H: f64 = 6.626_070_15e-34;
+        const C: f64 = 299_792_458.0;
+        const K: f64 = 1.380_649e-23;
+
+        let c1 = 2.0 * H * C * C;
+        let c2 = H * C / K;
+        let l = nm * 1e-9;
+
+        c1 / (l.powi(5) * ((c2 / (l * temperature)).exp() - 1.0)) * 1e-9
+    }
+
+    /// Returns the spectral radiance of a Planckian (blackbody) radiator at
+    /// the given `temperature`, in Kelvin, sampled over `shape`. Values are
+    /// in W·sr⁻¹·m⁻²·nm⁻¹, so the result can be used directly as a
+    /// physically based emitter
+    pub fn blackbody_radiance(temperature: f64, shape: SpdShape<f64>) -> VSPD {
+        shape
+            .iter()
+            .map(|nm| (nm, planck(nm, temperature)))
+            .collect()
+    }
+
+    /// Returns the spectral power distribution of a Planckian (blackbody)
+    /// radiator at the given `temperature`, in Kelvin, sampled over `shape`
+    /// and normalized to 100 at 560nm, matching the convention used for the
+    /// CIE illuminants
+    pub fn blackbody(temperature: f64, shape: SpdShape<f64>) -> VSPD {
+        let norm = 100.0 / planck(560.0, temperature);
+        shape
+            .iter()
+            .map(|nm| (nm, planck(nm, temperature) * norm))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{spd, xy};
+    use crate::cmf::{CIE_1931_2_DEGREE, CIE_1964_10_DEGREE};
+    use crate::*;
+
+    fn check_white(ill: &VSPD, cmf: &CMF, expected: XYY<f64>) {
+        let shape = SpdShape::new(360.0, 830.0, 5.0);
+        let xyz = VSPD::constant(shape, 1.0).to_xyz(ill, cmf);
+        let c = XYY::from_xyz(xyz);
+        assert!((c.x - expected.x).abs() < 3e-4, "{} != {}", c.x, expected.x);
+        assert!((c.y - expected.y).abs() < 3e-4, "{} != {}", c.y, expected.y);
+    }
+
+    #[test]
+    fn white_points_2_degree() {
+        let cases: [(&VSPD, XYY<f64>); 12] = [
+            (&spd::A, xy::A),
+            (&spd::F1, xy::F1),
+            (&spd::F2, xy::F2),
+            (&spd::F3, xy::F3),
+            (&spd::F4, xy::F4),
+            (&spd::F5, xy::F5),
+            (&spd::F6, xy::F6),
+            (&spd::F7, xy::F7),
+            (&spd::F8, xy::F8),
+            (&spd::F9, xy::F9),
+            (&spd::F11, xy::F11),
+            (&spd::F12, xy::F12),
+        ];
+        for (ill, expected) in cases.iter() {
+            check_white(ill, &CIE_1931_2_DEGREE, *expected);
+        }
+    }
+
+    #[test]
+    fn white_points_10_degree() {
+        let cases: [(&VSPD, XYY<f64>); 15] = [
+            (&spd::D50, xy::D50_10),
+            (&spd::D55, xy::D55_10),
+            (&spd::D65, xy::D65_10),
+            (&spd::A, xy::A_10),
+            (&spd::F1, xy::F1_10),
+            (&spd::F2, xy::F2_10),
+            (&spd::F3, xy::F3_10),
+            (&spd::F4, xy::F4_10),
+            (&spd::F5, xy::F5_10),
+            (&spd::F6, xy::F6_10),
+            (&spd::F7, xy::F7_10),
+            (&spd::F8, xy::F8_10),
+            (&spd::F9, xy::F9_10),
+            (&spd::F11, xy::F11_10),
+            (&spd::F12, xy::F12_10),
+        ];
+        for (ill, expected) in cases.iter() {
+            check_white(ill, &CIE_1964_10_DEGREE, *expected);
+        }
+    }
+
+    #[test]
+    fn daylight_chromaticity() {
+        let d65 = xy::daylight(6500.0 * 1.4388 / 1.4380);
+        assert!((d65.x - xy::D65.x).abs() < 2e-4);
+        assert!((d65.y - xy::D65.y).abs() < 2e-4);
+
+        let d50 = xy::daylight(5000.0 * 1.4388 / 1.4380);
+        assert!((d50.x - xy::D50.x).abs() < 2e-4);
+        assert!((d50.y - xy::D50.y).abs() < 2e-4);
+    }
+
+    #[test]
+    fn daylight_spd() {
+        for (cct, table) in [
+            (5000.0, &*spd::D50),
+            (5500.0, &*spd::D55),
+            (6500.0, &*spd::D65),
+        ]
+        .iter()
+        {
+            let d = spd::daylight(cct * 1.4388 / 1.4380);
+            for s in table.iter() {
+                let v = d.iter().find(|x| x.nm == s.nm).unwrap().v;
+                assert!((v - s.v).abs() < 0.1, "{}nm: {} != {}", s.nm, v, s.v);
+            }
+        }
+
+        for cct in [4000.0, 5003.0, 9300.0, 25000.0].iter() {
+            let c = xy::daylight(*cct);
+            check_white(&spd::daylight(*cct), &CIE_1931_2_DEGREE, c);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn daylight_out_of_range() {
+        xy::daylight(3000.0);
+    }
+
+    #[test]
+    fn blackbody_spd() {
+        // Illuminant A is defined with the pre-1968 c2 = 1.435e-2 at 2848K,
+        // which is equivalent to 2856K with the current value of c2
+        let t = 2848.0 * 1.4388 / 1.435;
+        let a = spd::blackbody(t, SpdShape::new(300.0, 830.0, 5.0));
+        for (s, r) in a.iter().zip(spd::A.iter()) {
+            assert!((s.v - r.v).abs() / r.v < 2e-4, "{:?} != {:?}", s, r);
+        }
+
+        // Wien's displacement law gives the peak at b / T
+        let b = 2.897_771_955e6;
+        let bb =
+            spd::blackbody_radiance(5000.0, SpdShape::new(380.0, 780.0, 1.0));
+        let peak = bb
+            .iter()
+            .fold(bb.first(), |a, s| if s.v > a.v { s } else { a });
+        assert!((peak.nm - b / 5000.0).abs() <= 1.0);
+    }
+
+    #[test]
+    fn blackbody_chromaticity() {
+        let shape = SpdShape::new(360.0, 830.0, 1.0);
+        for t in
+            [1667.0, 2000.0, 2856.0, 4000.0, 6500.0, 10000.0, 25000.0].iter()
+        {
+            let c = xy::blackbody(*t);
+            let xyz = VSPD::constant(shape, 1.0)
+                .to_xyz(&spd::blackbody(*t, shape), &CIE_1931_2_DEGREE);
+            let r = XYY::from_xyz(xyz);
+            assert!((c.x - r.x).abs() < 5e-4, "{} != {}", c.x, r.x);
+            assert!((c.y - r.y).abs() < 5e-4, "{} != {}", c.y, r.y);
+        }
     }
 }
@@ -0,0 +1,330 @@
+//! Jzazbz color space and its cylindrical JzCzhz form, with the ΔEz color
+//! difference.
+//!
+//! Jzazbz is designed for high dynamic range and wide gamut imagery. XYZ
+//! inputs are absolute, relative to D65 and in cd/m². RGB inputs are linear
+//! ITU-R BT.2020, also in cd/m².
+//!
+//! See Safdar et al., "Perceptually uniform color space for image signals
+//! including high dynamic range and wide gamut" (2017).
+#![allow(non_snake_case)]
+#![allow(clippy::excessive_precision, clippy::unreadable_literal)]
+use super::color_space_rgb::model_f64::ITUR_BT2020;
+use super::math::*;
+use super::rgb::*;
+use super::xyz::*;
+
+use numeric_literals::replace_float_literals;
+
+/// Jzazbz colour value
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Jzazbz<T>
+where
+    T: Real,
+{
+    pub Jz: T,
+    pub az: T,
+    pub bz: T,
+}
+
+/// Short constructor for a Jzazbz
+pub fn jzazbz<T>(Jz: T, az: T, bz: T) -> Jzazbz<T>
+where
+    T: Real,
+{
+    Jzazbz { Jz, az, bz }
+}
+
+/// JzCzhz colour value: the cylindrical form of [Jzazbz], with hue `hz` in
+/// degrees
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct JzCzhz<T>
+where
+    T: Real,
+{
+    pub Jz: T,
+    pub Cz: T,
+    pub hz: T,
+}
+
+/// Short constructor for a JzCzhz
+pub fn jzczhz<T>(Jz: T, Cz: T, hz: T) -> JzCzhz<T>
+where
+    T: Real,
+{
+    JzCzhz { Jz, Cz, hz }
+}
+
+#[replace_float_literals(T::from(literal).unwrap())]
+fn xyz_to_lms<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    #[rustfmt::skip]
+    let m = Matrix33::<T>::new([
+         0.41478972, 0.579999, 0.0146480,
+        -0.2015100,  1.120649, 0.0531008,
+        -0.0166008,  0.264800, 0.6684799,
+    ]);
+    m
+}
+
+#[replace_float_literals(T::from(literal).unwrap())]
+fn lms_to_iab<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    #[rustfmt::skip]
+    let m = Matrix33::<T>::new([
+        0.5,       0.5,       0.0,
+        3.524000, -4.066708,  0.542708,
+        0.199076,  1.096799, -1.295875,
+    ]);
+    m
+}
+
+/// Inverse of [xyz_to_lms]
+#[replace_float_literals(T::from(literal).unwrap())]
+fn lms_to_xyz<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    #[rustfmt::skip]
+    let m = Matrix33::<T>::new([
+          1.9242264357876067, -1.0047923125953655,  0.03765140403061801,
+         0.35031676209499907,  0.7264811939316552, -0.06538442294808502,
+        -0.09098281098284758,  -0.312728290523074,   1.5227665613052606,
+    ]);
+    m
+}
+
+/// Inverse of [lms_to_iab]
+#[replace_float_literals(T::from(literal).unwrap())]
+fn iab_to_lms<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    #[rustfmt::skip]
+    let m = Matrix33::<T>::new([
+        1.0,   0.1386050432715393,  0.058047316156118876,
+        1.0,  -0.1386050432715393, -0.058047316156118876,
+        1.0, -0.09601924202631895,    -0.811891896056039,
+    ]);
+    m
+}
+
+// Jzazbz uses the PQ curve with a modified exponent
+const B: f64 = 1.15;
+const G: f64 = 0.66;
+const M1: f64 = 2610.0 / 16384.0;
+const M2: f64 = 1.7 * 2523.0 / 32.0;
+const C1: f64 = 3424.0 / 4096.0;
+const C2: f64 = 2413.0 / 128.0;
+const C3: f64 = 2392.0 / 128.0;
+const D: f64 = -0.56;
+const D0: f64 = 1.6295499532821566e-11;
+
+fn pq<T>(L: T) -> T
+where
+    T: Real,
+{
+    let c = |x: f64| T::from(x).unwrap();
+    let Y_m1 = (L.max(T::zero()) / c(10000.0)).powf(c(M1));
+    ((c(C1) + c(C2) * Y_m1) / (T::one() + c(C3) * Y_m1)).powf(c(M2))
+}
+
+fn pq_inverse<T>(E: T) -> T
+where
+    T: Real,
+{
+    let c = |x: f64| T::from(x).unwrap();
+    let E_m2 = E.max(T::zero()).powf(T::one() / c(M2));
+    c(10000.0)
+        * ((E_m2 - c(C1)).max(T::zero()) / (c(C2) - c(C3) * E_m2))
+            .powf(T::one() / c(M1))
+}
+
+/// Convert an XYZ colour relative to D65, in cd/m², to Jzazbz
+pub fn xyz_to_jzazbz<T, X: Into<XYZ<T>>>(xyz: X) -> Jzazbz<T>
+where
+    T: Real,
+{
+    let c = |x: f64| T::from(x).unwrap();
+    let xyz: XYZ<T> = xyz.into();
+
+    let xyz_p = XYZ::new(
+        c(B) * xyz.x - c(B - 1.0) * xyz.z,
+        c(G) * xyz.y - c(G - 1.0) * xyz.x,
+        xyz.z,
+    );
+
+    let lms = xyz_to_lms() * xyz_p;
+    let lms = XYZ::new(pq(lms.x), pq(lms.y), pq(lms.z));
+    let iab = lms_to_iab() * lms;
+
+    let Jz = c(1.0 + D) * iab.x / (T::one() + c(D) * iab.x) - c(D0);
+    jzazbz(Jz, iab.y, iab.z)
+}
+
+/// Convert a Jzazbz colour to XYZ relative to D65, in cd/m²
+pub fn jzazbz_to_xyz<T, C: Into<Jzazbz<T>>>(c: C) -> XYZ<T>
+where
+    T: Real,
+{
+    let f = |x: f64| T::from(x).unwrap();
+    let c: Jzazbz<T> = c.into();
+
+    let Jz = c.Jz + f(D0);
+    let Iz = Jz / (f(1.0 + D) - f(D) * Jz);
+
+    let lms = iab_to_lms() * XYZ::new(Iz, c.az, c.bz);
+    let lms = XYZ::new(pq_inverse(lms.x), pq_inverse(lms.y), pq_inverse(lms.z));
+    let xyz_p = lms_to_xyz() * lms;
+
+    let x = (xyz_p.x + f(B - 1.0) * xyz_p.z) / f(B);
+    let y = (xyz_p.y + f(G - 1.0) * x) / f(G);
+
+    XYZ::new(x, y, xyz_p.z)
+}
+
+/// Convert linear ITU-R BT.2020 RGB in cd/m² to Jzazbz
+pub fn rgb_to_jzazbz<T>(rgb: RGBf<T>) -> Jzazbz<T>
+where
+    T: Real,
+{
+    xyz_to_jzazbz(
+        matrix_from_f64::<T>(&ITUR_BT2020.xf_rgb_to_xyz)
+            * XYZ::new(rgb.r, rgb.g, rgb.b),
+    )
+}
+
+/// Convert a Jzazbz colour to linear ITU-R BT.2020 RGB in cd/m²
+pub fn jzazbz_to_rgb<T, C: Into<Jzazbz<T>>>(c: C) -> RGBf<T>
+where
+    T: Real,
+{
+    let xyz =
+        matrix_from_f64::<T>(&ITUR_BT2020.xf_xyz_to_rgb) * jzazbz_to_xyz(c);
+    rgbf(xyz.x, xyz.y, xyz.z)
+}
+
+impl<T> From<Jzazbz<T>> for JzCzhz<T>
+where
+    T: Real,
+{
+    #[replace_float_literals(T::from(literal).unwrap())]
+    fn from(c: Jzazbz<T>) -> JzCzhz<T> {
+        let h = atan2(c.bz, c.az).to_degrees();
+        let h = if h < 0.0 { h + 360.0 } else { h };
+        jzczhz(c.Jz, hypot(c.az, c.bz), h)
+    }
+}
+
+impl<T> From<JzCzhz<T>> for Jzazbz<T>
+where
+    T: Real,
+{
+    fn from(c: JzCzhz<T>) -> Jzazbz<T> {
+        let h = c.hz.to_radians();
+        jzazbz(c.Jz, c.Cz * cos(h), c.Cz * sin(h))
+    }
+}
+
+/// Compute the difference between two Jzazbz colors according to the ΔEz
+/// formula.
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn delta_Ez<T>(c1: Jzazbz<T>, c2: Jzazbz<T>) -> T
+where
+    T: Real,
+{
+    let c1 = JzCzhz::from(c1);
+    let c2 = JzCzhz::from(c2);
+
+    let delta_h = (c1.hz - c2.hz).to_radians();
+    let delta_H = 2.0 * (c1.Cz * c2.Cz).sqrt() * (delta_h / 2.0).sin();
+
+    (sqr(c1.Jz - c2.Jz) + sqr(c1.Cz - c2.Cz) + sqr(delta_H)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverse_matrices() {
+        let i = M3f64::make_identity();
+        for m in [
+            xyz_to_lms::<f64>() * lms_to_xyz(),
+            lms_to_iab::<f64>() * iab_to_lms(),
+        ]
+        .iter()
+        {
+            for (a, b) in m.x.iter().zip(i.x.iter()) {
+                assert!((a - b).abs() < 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn jzazbz_rgb_conversions() {
+        // the RGB path goes through the BT.2020 primaries
+        let rgb = rgbf(100.0f64, 100.0, 100.0);
+        let xyz = ITUR_BT2020.xf_rgb_to_xyz * XYZ::new(rgb.r, rgb.g, rgb.b);
+        let c = rgb_to_jzazbz(rgb);
+        let c_2: Jzazbz<f64> = xyz_to_jzazbz(xyz);
+        assert!((c.Jz - c_2.Jz).abs() < 1e-14);
+        assert!((c.az - c_2.az).abs() < 1e-14);
+        assert!((c.bz - c_2.bz).abs() < 1e-14);
+
+        for rgb in crate::colorchecker::SRGB_LINEAR.values() {
+            let rgb = *rgb * 100.0;
+            let rgb_2 = jzazbz_to_rgb(rgb_to_jzazbz(rgb));
+            assert!((rgb.r - rgb_2.r).abs() < 1e-9);
+            assert!((rgb.g - rgb_2.g).abs() < 1e-9);
+            assert!((rgb.b - rgb_2.b).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn jzazbz_xyz_conversions() {
+        // reference values from the colour-science library
+        let xyz = xyz(0.20654008, 0.12197225, 0.05136952);
+        let c: Jzazbz<f64> = xyz_to_jzazbz(xyz);
+        assert!((c.Jz - 0.00535048).abs() < 1e-8);
+        assert!((c.az - 0.00924302).abs() < 1e-8);
+        assert!((c.bz - 0.00526007).abs() < 1e-8);
+
+        let xyz_2 = jzazbz_to_xyz(c);
+        assert!((xyz.x - xyz_2.x).abs() < 1e-10);
+        assert!((xyz.y - xyz_2.y).abs() < 1e-10);
+        assert!((xyz.z - xyz_2.z).abs() < 1e-10);
+
+        for xyz in crate::colorchecker::XYZ_D65.values() {
+            let xyz = *xyz * 10.0;
+            let c: Jzazbz<f64> = xyz_to_jzazbz(xyz);
+            let xyz_2 = jzazbz_to_xyz(c);
+            assert!((xyz.x - xyz_2.x).abs() < 1e-9);
+            assert!((xyz.y - xyz_2.y).abs() < 1e-9);
+            assert!((xyz.z - xyz_2.z).abs() < 1e-9);
+
+            let c_2: Jzazbz<f64> = JzCzhz::from(c).into();
+            assert!((c.az - c_2.az).abs() < 1e-14);
+            assert!((c.bz - c_2.bz).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn delta_ez() {
+        let c1 = jzazbz(0.01, 0.003, -0.004);
+        let c2 = jzazbz(0.02, 0.003, -0.004);
+        assert!((delta_Ez(c1, c2) - 0.01).abs() < 1e-14);
+
+        // for colours of equal lightness and chroma, ΔEz is the chord
+        // between them on the hue circle
+        let c1 = jzazbz(0.01, 0.003, 0.004);
+        let c2 = jzazbz(0.01, -0.004, 0.003);
+        assert!((delta_Ez(c1, c2) - 0.005 * 2.0f64.sqrt()).abs() < 1e-14);
+    }
+}
@@ -124,6 +124,123 @@ fn test_lab_xyz_conversions() {
     }
 }
 
+/// LCh(ab) colour value: the cylindrical form of [Lab], with chroma `C` and
+/// hue `h` in degrees
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[allow(non_snake_case)]
+pub struct LCh<T> where T: Real {
+    pub L: T,
+    pub C: T,
+    pub h: T,
+}
+
+/// Short constructor for an LCh
+#[allow(non_snake_case)]
+pub fn lch<T>(L: T, C: T, h: T) -> LCh<T> where T: Real {
+    LCh { L, C, h }
+}
+
+impl<T> From<Lab<T>> for LCh<T> where T: Real {
+    #[replace_float_literals(T::from(literal).unwrap())]
+    fn from(c: Lab<T>) -> LCh<T> {
+        let h = atan2(c.b, c.a).to_degrees();
+        let h = if h < 0.0 { h + 360.0 } else { h };
+        lch(c.L, hypot(c.a, c.b), h)
+    }
+}
+
+impl<T> From<LCh<T>> for Lab<T> where T: Real {
+    fn from(c: LCh<T>) -> Lab<T> {
+        let h = c.h.to_radians();
+        lab(c.L, c.C * cos(h), c.C * sin(h))
+    }
+}
+
+/// Interpolate between the hue angles `h1` and `h2`, in degrees, by `t`,
+/// taking the shortest way around the hue circle. The result is in the range
+/// [0, 360).
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn lerp_hue<T>(h1: T, h2: T, t: T) -> T where T: Real {
+    let d = (h2 - h1) % 360.0;
+    let d = if d > 180.0 {
+        d - 360.0
+    } else if d < -180.0 {
+        d + 360.0
+    } else {
+        d
+    };
+
+    let h = (h1 + d * t) % 360.0;
+    if h < 0.0 { h + 360.0 } else { h }
+}
+
+/// Blend the two colours `c1` and `c2` by `t` in LCh, interpolating L and C
+/// linearly and hue along the shortest arc. If either colour is achromatic
+/// its hue is meaningless, so the hue of the other colour is used throughout.
+#[allow(non_snake_case)]
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn mix_lch<T>(c1: LCh<T>, c2: LCh<T>, t: T) -> LCh<T> where T: Real {
+    let L = c1.L + (c2.L - c1.L) * t;
+    let C = c1.C + (c2.C - c1.C) * t;
+    let h = if c1.C == 0.0 {
+        c2.h
+    } else if c2.C == 0.0 {
+        c1.h
+    } else {
+        lerp_hue(c1.h, c2.h, t)
+    };
+
+    lch(L, C, h)
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn test_lab_lch_conversions() {
+    let epsilon = 1e-12_f64;
+
+    for L in (0..100).step_by(5).map(|f| f as f64) {
+        for a in (-127..127).step_by(3).map(|f| f as f64) {
+            for b in (-127..127).step_by(3).map(|f| f as f64) {
+                let c = lab(L, a, b);
+                let c_lch: LCh<f64> = c.into();
+                assert!(c_lch.h >= 0.0 && c_lch.h < 360.0);
+
+                let c_2: Lab<f64> = c_lch.into();
+                assert!((c.L - c_2.L).abs() < epsilon);
+                assert!((c.a - c_2.a).abs() < epsilon);
+                assert!((c.b - c_2.b).abs() < epsilon);
+            }
+        }
+    }
+
+    let c: LCh<f64> = lab(50.0, 0.0, -10.0).into();
+    assert!((c.C - 10.0).abs() < epsilon);
+    assert!((c.h - 270.0).abs() < epsilon);
+}
+
+#[test]
+fn test_lch_mix() {
+    let epsilon = 1e-12_f64;
+
+    // the short way round from 350 to 10 passes through 0
+    assert!((lerp_hue(350.0, 10.0, 0.5) - 0.0).abs() < epsilon);
+    assert!((lerp_hue(350.0, 10.0, 0.25) - 355.0).abs() < epsilon);
+    assert!((lerp_hue(10.0, 350.0, 0.25) - 5.0).abs() < epsilon);
+    assert!((lerp_hue(90.0, 180.0, 0.5) - 135.0).abs() < epsilon);
+    assert!((lerp_hue(0.0, 720.0 + 90.0, 1.0) - 90.0).abs() < epsilon);
+
+    let c = mix_lch(lch(20.0, 40.0, 300.0), lch(80.0, 60.0, 40.0), 0.5);
+    assert!((c.L - 50.0).abs() < epsilon);
+    assert!((c.C - 50.0).abs() < epsilon);
+    assert!((c.h - 350.0).abs() < epsilon);
+
+    // blending towards grey keeps the hue of the chromatic colour
+    let c = mix_lch(lch(50.0, 40.0, 120.0), lch(50.0, 0.0, 0.0), 0.5);
+    assert!((c.C - 20.0).abs() < epsilon);
+    assert!((c.h - 120.0).abs() < epsilon);
+}
+
 /// Compute the difference between two L*a*b* colors according to the CIE 1976
 /// formula.
 #[allow(non_snake_case)]
@@ -222,6 +339,91 @@ pub fn delta_E_2000<T>(c1: Lab<T>, c2: Lab<T>) -> T where T: Real {
     ).sqrt()
 }
 
+/// Compute the difference between two L*a*b* colors according to the CIE 1994
+/// formula, where `c1` is the reference color.
+///
+/// The parametric weights `k_L`, `K_1` and `K_2` are (1, 0.045, 0.015) for
+/// graphic arts and (2, 0.048, 0.014) for textiles.
+#[allow(non_snake_case)]
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn delta_E_1994<T>(c1: Lab<T>, c2: Lab<T>, k_L: T, K_1: T, K_2: T) -> T where T: Real {
+    let C_1 = hypot(c1.a, c1.b);
+    let C_2 = hypot(c2.a, c2.b);
+
+    let delta_L = c1.L - c2.L;
+    let delta_C = C_1 - C_2;
+    let delta_H2 = sqr(c1.a - c2.a) + sqr(c1.b - c2.b) - sqr(delta_C);
+
+    let S_C = 1.0 + K_1 * C_1;
+    let S_H = 1.0 + K_2 * C_1;
+
+    (sqr(delta_L / k_L) + sqr(delta_C / S_C) + delta_H2 / sqr(S_H)).sqrt()
+}
+
+/// Compute the difference between two L*a*b* colors according to the CMC l:c
+/// formula, where `c1` is the reference color.
+///
+/// Use `l = 2, c = 1` for acceptability and `l = 1, c = 1` for
+/// perceptibility.
+#[allow(non_snake_case)]
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn delta_E_cmc<T>(c1: Lab<T>, c2: Lab<T>, l: T, c: T) -> T where T: Real {
+    let C_1 = hypot(c1.a, c1.b);
+    let C_2 = hypot(c2.a, c2.b);
+    let h_1 = atan2(c1.b, c1.a).to_degrees();
+    let h_1 = if h_1 < 0.0 { h_1 + 360.0 } else { h_1 };
+
+    let delta_L = c1.L - c2.L;
+    let delta_C = C_1 - C_2;
+    let delta_H2 = sqr(c1.a - c2.a) + sqr(c1.b - c2.b) - sqr(delta_C);
+
+    let S_L = if c1.L < 16.0 {
+        0.511
+    } else {
+        0.040975 * c1.L / (1.0 + 0.01765 * c1.L)
+    };
+    let S_C = 0.0638 * C_1 / (1.0 + 0.0131 * C_1) + 0.638;
+    let F = (C_1.powi(4) / (C_1.powi(4) + 1900.0)).sqrt();
+    let T = if h_1 >= 164.0 && h_1 <= 345.0 {
+        0.56 + abs(0.2 * cos((h_1 + 168.0).to_radians()))
+    } else {
+        0.36 + abs(0.4 * cos((h_1 + 35.0).to_radians()))
+    };
+    let S_H = S_C * (F * T + 1.0 - F);
+
+    (sqr(delta_L / (l * S_L)) + sqr(delta_C / (c * S_C)) + delta_H2 / sqr(S_H))
+        .sqrt()
+}
+
+/// Convert an L*a*b* color to the DIN99 color space defined in DIN 6176,
+/// returning the L99, a99 and b99 coordinates as a [Lab].
+///
+/// `k_E` and `k_CH` are the parametric weights for lightness and
+/// chroma/hue, both normally 1.
+#[allow(non_snake_case)]
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn lab_to_din99<T>(c: Lab<T>, k_E: T, k_CH: T) -> Lab<T> where T: Real {
+    let cos_16 = 16.0.to_radians().cos();
+    let sin_16 = 16.0.to_radians().sin();
+
+    let L_99 = 105.509 / k_E * (1.0 + 0.0158 * c.L).ln();
+
+    let e = c.a * cos_16 + c.b * sin_16;
+    let f = 0.7 * (c.b * cos_16 - c.a * sin_16);
+    let G = hypot(e, f);
+    let C_99 = (1.0 + 0.045 * G).ln() / (0.045 * k_CH * k_E);
+    let h_99 = atan2(f, e);
+
+    lab(L_99, C_99 * cos(h_99), C_99 * sin(h_99))
+}
+
+/// Compute the difference between two L*a*b* colors as the Euclidean
+/// distance between them in the DIN99 color space.
+#[allow(non_snake_case)]
+pub fn delta_E_din99<T>(c1: Lab<T>, c2: Lab<T>, k_E: T, k_CH: T) -> T where T: Real {
+    delta_E_1976(lab_to_din99(c1, k_E, k_CH), lab_to_din99(c2, k_E, k_CH))
+}
+
 #[cfg(test)]
 fn round_to_places(x: f32, p: i32) -> f32 {
     (x * 10f32.powi(p)).round() / 10f32.powi(p)
@@ -300,3 +502,55 @@ fn test_delta_e() {
     let dE_14 = delta_E_2000(c14_1, c14_2);
     assert_eq!(round_to_places(dE_14, 4), 4.8045);
 }
+
+#[test]
+#[allow(non_snake_case)]
+fn test_delta_e_1994_cmc_din99() {
+    // Pairs from the Sharma et al. CIEDE2000 test data, with
+    // CIE94 (graphic arts), CIE94 (textiles), CMC 2:1, CMC 1:1 and DIN99
+    // differences
+    let data: [((f64, f64, f64), (f64, f64, f64), [f64; 5]); 24] = [
+        ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), [1.3950, 1.4230, 1.7387, 1.7387, 1.4721]),
+        ((50.0, 3.1571, -77.2803), (50.0, 0.0, -82.7485), [1.9341, 1.9427, 2.4966, 2.4966, 1.9907]),
+        ((50.0, 2.8361, -74.0200), (50.0, 0.0, -82.7485), [2.4543, 2.4066, 3.3049, 3.3049, 2.3965]),
+        ((50.0, -1.3802, -84.2814), (50.0, 0.0, -82.7485), [0.6845, 0.6980, 0.8574, 0.8574, 0.7331]),
+        ((50.0, -1.1848, -84.8006), (50.0, 0.0, -82.7485), [0.6696, 0.6719, 0.8833, 0.8833, 0.7099]),
+        ((50.0, -0.9009, -85.5211), (50.0, 0.0, -82.7485), [0.6919, 0.6772, 0.9782, 0.9782, 0.7120]),
+        ((50.0, 0.0, 0.0), (50.0, -1.0, 2.0), [2.2361, 2.2361, 3.5048, 3.5048, 1.5379]),
+        ((50.0, -1.0, 2.0), (50.0, 0.0, 0.0), [2.0316, 2.0193, 2.8793, 2.8793, 1.5379]),
+        ((50.0, 2.49, -0.001), (50.0, -2.49, 0.0009), [4.8007, 4.8122, 6.5784, 6.5784, 4.6323]),
+        ((50.0, 2.49, -0.001), (50.0, -2.49, 0.0010), [4.8007, 4.8122, 6.5784, 6.5784, 4.6323]),
+        ((50.0, 2.49, -0.001), (50.0, -2.49, 0.0011), [4.8007, 4.8122, 6.5784, 6.5784, 4.6323]),
+        ((50.0, 2.49, -0.001), (50.0, -2.49, 0.0012), [4.8007, 4.8122, 6.5784, 6.5784, 4.6323]),
+        ((50.0, -0.001, 2.49), (50.0, 0.0009, -2.49), [4.8007, 4.8122, 6.6749, 6.6749, 3.4809]),
+        ((50.0, -0.001, 2.49), (50.0, 0.0010, -2.49), [4.8007, 4.8122, 6.6749, 6.6749, 3.4809]),
+        ((50.0, 2.5, 0.0), (50.0, 0.0, -2.5), [3.4077, 3.4160, 4.6685, 4.6685, 3.1622]),
+        ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), [34.6892, 28.2503, 37.9233, 42.1088, 24.6177]),
+        ((50.0, 2.5, 0.0), (61.0, -5.0, 29.0), [29.4414, 27.7308, 38.4758, 39.4589, 17.8424]),
+        ((50.0, 2.5, 0.0), (56.0, -27.0, -3.0), [27.9141, 27.3286, 38.0618, 38.3601, 20.7062]),
+        ((50.0, 2.5, 0.0), (58.0, 24.0, 15.0), [24.9377, 23.8076, 33.3342, 33.9366, 17.4428]),
+        ((50.0, 2.5, 0.0), (50.0, 3.1736, 0.5854), [0.8221, 0.8194, 1.1440, 1.1440, 0.7634]),
+        ((60.2574, -34.0099, 36.2677), (60.4626, -34.1751, 39.4387), [1.3910, 1.3897, 1.4205, 1.4282, 1.1772]),
+        ((63.0109, -31.0961, -5.8663), (62.8187, -29.7946, -4.0864), [1.2481, 1.2441, 1.2474, 1.2548, 0.9875]),
+        ((22.7233, 20.0904, -46.6940), (23.0331, 14.9730, -42.5619), [2.5561, 2.5310, 3.0604, 3.0870, 2.6214]),
+        ((90.8027, -2.0831, 1.4410), (91.1528, -1.6435, 0.0447), [1.4195, 1.3858, 1.8891, 1.9010, 1.0042]),
+    ];
+
+    for (c1, c2, dE) in data.iter() {
+        let c1 = lab(c1.0, c1.1, c1.2);
+        let c2 = lab(c2.0, c2.1, c2.2);
+        assert!((delta_E_1994(c1, c2, 1.0, 0.045, 0.015) - dE[0]).abs() < 5e-5);
+        assert!((delta_E_1994(c1, c2, 2.0, 0.048, 0.014) - dE[1]).abs() < 5e-5);
+        assert!((delta_E_cmc(c1, c2, 2.0, 1.0) - dE[2]).abs() < 5e-5);
+        assert!((delta_E_cmc(c1, c2, 1.0, 1.0) - dE[3]).abs() < 5e-5);
+        assert!((delta_E_din99(c1, c2, 1.0, 1.0) - dE[4]).abs() < 5e-5);
+    }
+
+    // reference values from the colour-science library
+    let c1 = lab(100.0, 21.57210357, 272.2281935);
+    let c2 = lab(100.0, 426.67945353, 72.39590835);
+    assert!((delta_E_1994(c1, c2, 1.0, 0.045, 0.015) - 83.7792255).abs() < 1e-6);
+    assert!((delta_E_1994(c1, c2, 2.0, 0.048, 0.014) - 88.3355530).abs() < 1e-6);
+    assert!((delta_E_cmc(c1, c2, 2.0, 1.0) - 172.7047712).abs() < 1e-6);
+    assert!((delta_E_din99(c1, c2, 1.0, 1.0) - 66.1119282).abs() < 1e-6);
+}
@@ -114,15 +114,23 @@ pub mod math;
 pub use math::{M3f32, M3f64, Matrix33};
 
 pub mod color_space_rgb;
-pub use color_space_rgb::{decode, encode, model_f64::*, ColorSpaceRGB};
+pub use color_space_rgb::{
+    decode, encode, model_f64::*, ColorSpaceRGB, TransferCurve,
+};
+
+pub mod color_space_registry;
 
 pub mod chromaticity;
 pub use chromaticity::*;
 
 pub mod chromatic_adaptation;
 
+pub mod appearance;
+
+pub mod cct;
+
 pub mod vspd;
-pub use vspd::{SpdElement, SpdShape, VSPD};
+pub use vspd::{Integration, SpdElement, SpdShape, VSPD};
 
 pub mod uplifting;
 
@@ -134,7 +142,24 @@ pub use transform::*;
 
 pub mod lab;
 pub use lab::delta_E_2000 as delta_E;
-pub use lab::{lab, xyz_to_lab, Lab};
+pub use lab::{lab, lch, mix_lch, xyz_to_lab, LCh, Lab};
+
+pub mod luv;
+pub use luv::{lchuv, luv, xyz_to_luv, LChuv, Luv};
+
+pub mod oklab;
+pub use oklab::{oklab, oklch, xyz_to_oklab, Oklab, Oklch};
+
+pub mod ictcp;
+pub use ictcp::{ictcp, ICtCp};
+
+pub mod jzazbz;
+pub use jzazbz::{jzazbz, jzczhz, xyz_to_jzazbz, JzCzhz, Jzazbz};
 
 pub mod photometry;
 pub use photometry::spd_to_lumens;
+
+pub mod color_rendering;
+pub use color_rendering::{cri, cri_with_samples, tm30_with_samples};
+
+pub mod diagram;
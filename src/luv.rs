@@ -0,0 +1,174 @@
+//! CIE 1976 L*u*v* color space and its cylindrical LCh(uv) form.
+//!
+//! See http://www.brucelindbloom.com/index.html?Eqn_XYZ_to_Luv.html
+use super::chromaticity::UV;
+use super::math::*;
+use super::xyz::*;
+
+use numeric_literals::replace_float_literals;
+
+/// Luv colour value
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[allow(non_snake_case)]
+pub struct Luv<T>
+where
+    T: Real,
+{
+    pub L: T,
+    pub u: T,
+    pub v: T,
+}
+
+/// Short constructor for a Luv
+#[allow(non_snake_case)]
+pub fn luv<T>(L: T, u: T, v: T) -> Luv<T>
+where
+    T: Real,
+{
+    Luv { L, u, v }
+}
+
+/// Convert an XYZ color to a Luv colour with the given reference white.
+#[allow(non_snake_case)]
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn xyz_to_luv<T, X1: Into<XYZ<T>>, X2: Into<XYZ<T>>>(
+    xyz: X1,
+    ref_white: X2,
+) -> Luv<T>
+where
+    T: Real,
+{
+    let xyz: XYZ<T> = xyz.into();
+    let ref_white: XYZ<T> = ref_white.into();
+
+    if xyz.y <= 0.0 {
+        return luv(0.0, 0.0, 0.0);
+    }
+
+    let epsilon = 216.0 / 24389.0;
+    let kappa = 24389.0 / 27.0;
+
+    let y_r = xyz.y / ref_white.y;
+    let L = if y_r > epsilon {
+        116.0 * y_r.powf(1.0 / 3.0) - 16.0
+    } else {
+        kappa * y_r
+    };
+
+    let c = UV::from_xyz(xyz);
+    let c_r = UV::from_xyz(ref_white);
+
+    luv(L, 13.0 * L * (c.u - c_r.u), 13.0 * L * (c.v - c_r.v))
+}
+
+/// Convert a Luv color to an XYZ colour with the given reference white.
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn luv_to_xyz<T, X1: Into<Luv<T>>, X2: Into<XYZ<T>>>(
+    luv: X1,
+    ref_white: X2,
+) -> XYZ<T>
+where
+    T: Real,
+{
+    let luv: Luv<T> = luv.into();
+    let ref_white: XYZ<T> = ref_white.into();
+
+    if luv.L <= 0.0 {
+        return XYZ::from_scalar(0.0);
+    }
+
+    let epsilon = 216.0 / 24389.0;
+    let kappa = 24389.0 / 27.0;
+
+    let y = if luv.L > kappa * epsilon {
+        ((luv.L + 16.0) / 116.0).powi(3)
+    } else {
+        luv.L / kappa
+    } * ref_white.y;
+
+    let c_r = UV::from_xyz(ref_white);
+    let u = luv.u / (13.0 * luv.L) + c_r.u;
+    let v = luv.v / (13.0 * luv.L) + c_r.v;
+
+    let x = y * 9.0 * u / (4.0 * v);
+    let z = y * (12.0 - 3.0 * u - 20.0 * v) / (4.0 * v);
+
+    XYZ::new(x, y, z)
+}
+
+/// LCh(uv) colour value: the cylindrical form of [Luv], with hue `h` in
+/// degrees
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[allow(non_snake_case)]
+pub struct LChuv<T>
+where
+    T: Real,
+{
+    pub L: T,
+    pub C: T,
+    pub h: T,
+}
+
+/// Short constructor for an LChuv
+#[allow(non_snake_case)]
+pub fn lchuv<T>(L: T, C: T, h: T) -> LChuv<T>
+where
+    T: Real,
+{
+    LChuv { L, C, h }
+}
+
+impl<T> From<Luv<T>> for LChuv<T>
+where
+    T: Real,
+{
+    #[replace_float_literals(T::from(literal).unwrap())]
+    fn from(c: Luv<T>) -> LChuv<T> {
+        let h = atan2(c.v, c.u).to_degrees();
+        let h = if h < 0.0 { h + 360.0 } else { h };
+        lchuv(c.L, hypot(c.u, c.v), h)
+    }
+}
+
+impl<T> From<LChuv<T>> for Luv<T>
+where
+    T: Real,
+{
+    fn from(c: LChuv<T>) -> Luv<T> {
+        let h = c.h.to_radians();
+        luv(c.L, c.C * cos(h), c.C * sin(h))
+    }
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn test_luv_xyz_conversions() {
+    let d65: XYZ<f64> = crate::illuminant::xy::D65.into();
+    let epsilon = 2e-12_f64;
+
+    // sRGB red
+    let red: Luv<f64> = xyz_to_luv(xyz(41.24564, 21.26729, 1.93339), d65);
+    assert!((red.L - 53.24).abs() < 1e-2);
+    assert!((red.u - 175.02).abs() < 1e-2);
+    assert!((red.v - 37.76).abs() < 1e-2);
+
+    for L in (2..100).step_by(2).map(|f| f as f64) {
+        for u in (-100..100).step_by(2).map(|f| f as f64) {
+            for v in (-100..100).step_by(2).map(|f| f as f64) {
+                let luv = Luv { L, u, v };
+                let xyz = luv_to_xyz(luv, d65);
+                let luv_2: Luv<f64> = xyz_to_luv(xyz, d65);
+
+                assert!((luv.L - luv_2.L).abs() < epsilon);
+                assert!((luv.u - luv_2.u).abs() < epsilon);
+                assert!((luv.v - luv_2.v).abs() < epsilon);
+
+                let luv_3: Luv<f64> = LChuv::from(luv).into();
+                assert!((luv.u - luv_3.u).abs() < epsilon);
+                assert!((luv.v - luv_3.v).abs() < epsilon);
+            }
+        }
+    }
+}
@@ -1,14 +1,9 @@
 #[macro_export]
 macro_rules! vspd {
     ($($nm:expr => $v:expr,)*) => {{
-        let mut samples = Vec::new();
-        $(samples.push(crate::vspd::Sample::new($nm, $v));)*
-        VSPD::new(samples)
+        VSPD::new(vec![$(crate::vspd::Sample::new($nm, $v),)*])
     }};
     ($($nm:expr =>$v:expr),*) => {{
-        let mut samples = Vec::new();
-        $(samples.push(crate::vspd::Sample::new($nm, $v));)*
-        VSPD::new(samples)
+        VSPD::new(vec![$(crate::vspd::Sample::new($nm, $v)),*])
     }};
 }
-
@@ -306,6 +306,16 @@ impl From<M3f64> for M3f32 {
     }
 }
 
+/// Convert a 64-bit matrix to any real type, e.g. to use the matrices of the
+/// `model_f64` color spaces in generic code
+pub(crate) fn matrix_from_f64<T>(m: &M3f64) -> Matrix33<T> where T: Real {
+    let mut x = [T::zero(); 9];
+    for (x, m) in x.iter_mut().zip(m.x.iter()) {
+        *x = T::from(*m).unwrap();
+    }
+    Matrix33::new(x)
+}
+
 /// Index operator. Returns a slice of the underlying matrix to allow
 /// `m[i][j]` indexing
 impl<T> Index<usize> for Matrix33<T> where T: Real {
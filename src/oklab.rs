@@ -0,0 +1,308 @@
+//! Oklab color space and its cylindrical Oklch form.
+//!
+//! Oklab is defined relative to a D65 white point, so XYZ values relative to
+//! any other white should be adapted first using the chromatic_adaptation
+//! module.
+//!
+//! See https://bottosson.github.io/posts/oklab/
+use super::math::*;
+use super::rgb::*;
+use super::xyz::*;
+
+use numeric_literals::replace_float_literals;
+
+/// Oklab colour value
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[allow(non_snake_case)]
+pub struct Oklab<T>
+where
+    T: Real,
+{
+    pub L: T,
+    pub a: T,
+    pub b: T,
+}
+
+/// Short constructor for an Oklab
+#[allow(non_snake_case)]
+pub fn oklab<T>(L: T, a: T, b: T) -> Oklab<T>
+where
+    T: Real,
+{
+    Oklab { L, a, b }
+}
+
+/// Oklch colour value: the cylindrical form of [Oklab], with hue `h` in
+/// degrees
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+#[allow(non_snake_case)]
+pub struct Oklch<T>
+where
+    T: Real,
+{
+    pub L: T,
+    pub C: T,
+    pub h: T,
+}
+
+/// Short constructor for an Oklch
+#[allow(non_snake_case)]
+pub fn oklch<T>(L: T, C: T, h: T) -> Oklch<T>
+where
+    T: Real,
+{
+    Oklch { L, C, h }
+}
+
+#[replace_float_literals(T::from(literal).unwrap())]
+fn xyz_to_lms<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    Matrix33::new([
+        0.8189330101,
+        0.3618667424,
+        -0.1288597137,
+        0.0329845436,
+        0.9293118715,
+        0.0361456387,
+        0.0482003018,
+        0.2643662691,
+        0.6338517070,
+    ])
+}
+
+#[replace_float_literals(T::from(literal).unwrap())]
+fn srgb_to_lms<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    Matrix33::new([
+        0.4122214708,
+        0.5363325363,
+        0.0514459929,
+        0.2119034982,
+        0.6806995451,
+        0.1073969566,
+        0.0883024619,
+        0.2817188376,
+        0.6299787005,
+    ])
+}
+
+#[replace_float_literals(T::from(literal).unwrap())]
+fn lms_to_lab<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    Matrix33::new([
+        0.2104542553,
+        0.7936177850,
+        -0.0040720468,
+        1.9779984951,
+        -2.4285922050,
+        0.4505937099,
+        0.0259040371,
+        0.7827717662,
+        -0.8086757660,
+    ])
+}
+
+/// Inverse of [xyz_to_lms]
+#[replace_float_literals(T::from(literal).unwrap())]
+fn lms_to_xyz<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    Matrix33::new([
+        1.2270138511035211,
+        -0.5577999806518222,
+        0.28125614896646783,
+        -0.04058017842328059,
+        1.11225686961683,
+        -0.0716766786656012,
+        -0.07638128450570689,
+        -0.4214819784180127,
+        1.5861632204407947,
+    ])
+}
+
+/// Inverse of [srgb_to_lms]
+#[replace_float_literals(T::from(literal).unwrap())]
+fn lms_to_srgb<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    Matrix33::new([
+        4.076741661347994,
+        -3.3077115904081933,
+        0.2309699287294279,
+        -1.268438004092176,
+        2.6097574006633715,
+        -0.3413193963102196,
+        -0.004196086541837109,
+        -0.7034186144594496,
+        1.7076147009309448,
+    ])
+}
+
+/// Inverse of [lms_to_lab]
+#[replace_float_literals(T::from(literal).unwrap())]
+fn lab_to_lms<T>() -> Matrix33<T>
+where
+    T: Real,
+{
+    Matrix33::new([
+        0.9999999984505198,
+        0.39633779217376786,
+        0.2158037580607588,
+        1.0000000088817609,
+        -0.10556134232365635,
+        -0.06385417477170591,
+        1.0000000546724108,
+        -0.08948418209496575,
+        -1.2914855378640917,
+    ])
+}
+
+fn lms_to_oklab<T>(lms: XYZ<T>) -> Oklab<T>
+where
+    T: Real,
+{
+    let lms = XYZ::new(lms.x.cbrt(), lms.y.cbrt(), lms.z.cbrt());
+    let lab = lms_to_lab() * lms;
+    oklab(lab.x, lab.y, lab.z)
+}
+
+fn oklab_to_lms<T>(c: Oklab<T>) -> XYZ<T>
+where
+    T: Real,
+{
+    let lms = lab_to_lms() * XYZ::new(c.L, c.a, c.b);
+    XYZ::new(lms.x.powi(3), lms.y.powi(3), lms.z.powi(3))
+}
+
+/// Convert an XYZ colour relative to D65 to Oklab
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn xyz_to_oklab<T, X: Into<XYZ<T>>>(xyz: X) -> Oklab<T>
+where
+    T: Real,
+{
+    let xyz: XYZ<T> = xyz.into();
+    lms_to_oklab(xyz_to_lms() * (xyz / XYZ::from_scalar(100.0)))
+}
+
+/// Convert an Oklab colour to XYZ relative to D65
+#[replace_float_literals(T::from(literal).unwrap())]
+pub fn oklab_to_xyz<T, C: Into<Oklab<T>>>(c: C) -> XYZ<T>
+where
+    T: Real,
+{
+    lms_to_xyz() * oklab_to_lms(c.into()) * 100.0
+}
+
+/// Convert a scene-referred (i.e. linear) sRGB colour to Oklab
+pub fn rgb_to_oklab<T>(rgb: RGBf<T>) -> Oklab<T>
+where
+    T: Real,
+{
+    let lms = srgb_to_lms() * rgb;
+    lms_to_oklab(XYZ::new(lms.r, lms.g, lms.b))
+}
+
+/// Convert an Oklab colour to scene-referred (i.e. linear) sRGB
+pub fn oklab_to_rgb<T, C: Into<Oklab<T>>>(c: C) -> RGBf<T>
+where
+    T: Real,
+{
+    let lms = oklab_to_lms(c.into());
+    lms_to_srgb() * rgbf(lms.x, lms.y, lms.z)
+}
+
+impl<T> From<Oklab<T>> for Oklch<T>
+where
+    T: Real,
+{
+    #[replace_float_literals(T::from(literal).unwrap())]
+    fn from(c: Oklab<T>) -> Oklch<T> {
+        let h = atan2(c.b, c.a).to_degrees();
+        let h = if h < 0.0 { h + 360.0 } else { h };
+        oklch(c.L, hypot(c.a, c.b), h)
+    }
+}
+
+impl<T> From<Oklch<T>> for Oklab<T>
+where
+    T: Real,
+{
+    fn from(c: Oklch<T>) -> Oklab<T> {
+        let h = c.h.to_radians();
+        oklab(c.L, c.C * cos(h), c.C * sin(h))
+    }
+}
+
+#[cfg(test)]
+fn round_to_places(x: f64, p: i32) -> f64 {
+    (x * 10f64.powi(p)).round() / 10f64.powi(p)
+}
+
+#[test]
+fn test_oklab_inverse_matrices() {
+    let i = M3f64::make_identity();
+    for m in [
+        xyz_to_lms::<f64>() * lms_to_xyz(),
+        srgb_to_lms::<f64>() * lms_to_srgb(),
+        lms_to_lab::<f64>() * lab_to_lms(),
+    ]
+    .iter()
+    {
+        for (a, b) in m.x.iter().zip(i.x.iter()) {
+            assert!((a - b).abs() < 1e-14);
+        }
+    }
+}
+
+#[test]
+fn test_oklab_xyz_conversions() {
+    // reference values from https://bottosson.github.io/posts/oklab/
+    let table = [
+        (xyz(95.0, 100.0, 108.9), oklab(1.0, 0.0, 0.0)),
+        (xyz(100.0, 0.0, 0.0), oklab(0.450, 1.236, -0.019)),
+        (xyz(0.0, 100.0, 0.0), oklab(0.922, -0.671, 0.263)),
+        (xyz(0.0, 0.0, 100.0), oklab(0.153, -1.415, -0.449)),
+    ];
+
+    for (x, c) in table.iter() {
+        let c_2: Oklab<f64> = xyz_to_oklab(*x);
+        assert_eq!(round_to_places(c_2.L, 3), c.L);
+        assert_eq!(round_to_places(c_2.a, 3), c.a);
+        assert_eq!(round_to_places(c_2.b, 3), c.b);
+
+        let x_2 = oklab_to_xyz(c_2);
+        assert!((x.x - x_2.x).abs() < 1e-10);
+        assert!((x.y - x_2.y).abs() < 1e-10);
+        assert!((x.z - x_2.z).abs() < 1e-10);
+
+        let c_3: Oklab<f64> = Oklch::from(c_2).into();
+        assert!((c_2.a - c_3.a).abs() < 1e-14);
+        assert!((c_2.b - c_3.b).abs() < 1e-14);
+    }
+}
+
+#[test]
+fn test_oklab_rgb_conversions() {
+    let white = rgb_to_oklab(rgbf(1.0f64, 1.0, 1.0));
+    assert!((white.L - 1.0).abs() < 1e-4);
+    assert!(white.a.abs() < 1e-4);
+    assert!(white.b.abs() < 1e-4);
+
+    for rgb in crate::colorchecker::SRGB_LINEAR.values() {
+        let c = rgb_to_oklab(*rgb);
+        let rgb_2 = oklab_to_rgb(c);
+        assert!((rgb.r - rgb_2.r).abs() < 1e-10);
+        assert!((rgb.g - rgb_2.g).abs() < 1e-10);
+        assert!((rgb.b - rgb_2.b).abs() < 1e-10);
+    }
+}
@@ -1,11 +1,320 @@
+//! Photometric quantities computed from spectral radiometric quantities.
+//!
+//! Spectra are [VSPD]s with wavelengths in nm and values in radiometric units
+//! per nm, e.g. W/nm for radiant flux, W/m²/nm for irradiance or
+//! W/sr/m²/nm for radiance. The corresponding photometric quantity is
+//! lumens, lux or cd/m² respectively.
+//!
+//! Photopic quantities use the CIE 1924 luminous efficiency function V(λ),
+//! which is the `y_bar` function of [CIE_1931_2_DEGREE]. Scotopic quantities
+//! use the CIE 1951 function V'(λ), [SCOTOPIC_1951]. Mesopic quantities follow
+//! the recommended system of CIE 191:2010.
+use crate::cmf::{CIE_1931_2_DEGREE, CMF};
 use crate::vspd::*;
-use crate::cmf::CMF;
+use lazy_static::lazy_static;
 
+/// Maximum photopic luminous efficacy, in lm/W
+pub const K_M: f64 = 683.0;
+
+/// Maximum scotopic luminous efficacy, in lm/W
+pub const K_M_SCOTOPIC: f64 = 1700.0;
+
+// CIE 191 mesopic parameters
+const MESOPIC_A: f64 = 0.7670;
+const MESOPIC_B: f64 = 0.3334;
+// V'(λ) at 555nm, the peak of V(λ)
+const V_PRIME_555: f64 = 0.402;
+const MESOPIC_PHOTOPIC_LIMIT: f64 = 5.0;
+const MESOPIC_SCOTOPIC_LIMIT: f64 = 0.005;
+
+lazy_static! {
+    /// CIE 1951 scotopic luminous efficiency function V'(λ), tabulated at 5nm
+    pub static ref SCOTOPIC_1951: VSPD = vspd!(
+        380.0 => 0.000589,
+        385.0 => 0.001108,
+        390.0 => 0.002209,
+        395.0 => 0.004530,
+        400.0 => 0.009290,
+        405.0 => 0.018520,
+        410.0 => 0.034840,
+        415.0 => 0.060400,
+        420.0 => 0.096600,
+        425.0 => 0.143600,
+        430.0 => 0.199800,
+        435.0 => 0.262500,
+        440.0 => 0.328100,
+        445.0 => 0.393100,
+        450.0 => 0.455000,
+        455.0 => 0.513000,
+        460.0 => 0.567000,
+        465.0 => 0.620000,
+        470.0 => 0.676000,
+        475.0 => 0.734000,
+        480.0 => 0.793000,
+        485.0 => 0.851000,
+        490.0 => 0.904000,
+        495.0 => 0.949000,
+        500.0 => 0.982000,
+        505.0 => 0.998000,
+        510.0 => 0.997000,
+        515.0 => 0.975000,
+        520.0 => 0.935000,
+        525.0 => 0.880000,
+        530.0 => 0.811000,
+        535.0 => 0.733000,
+        540.0 => 0.650000,
+        545.0 => 0.564000,
+        550.0 => 0.481000,
+        555.0 => 0.402000,
+        560.0 => 0.328800,
+        565.0 => 0.263900,
+        570.0 => 0.207600,
+        575.0 => 0.160200,
+        580.0 => 0.121200,
+        585.0 => 0.089900,
+        590.0 => 0.065500,
+        595.0 => 0.046900,
+        600.0 => 0.033150,
+        605.0 => 0.023120,
+        610.0 => 0.015930,
+        615.0 => 0.010880,
+        620.0 => 0.007370,
+        625.0 => 0.004970,
+        630.0 => 0.003335,
+        635.0 => 0.002235,
+        640.0 => 0.001497,
+        645.0 => 0.001005,
+        650.0 => 0.000677,
+        655.0 => 0.000459,
+        660.0 => 0.000313,
+        665.0 => 0.000215,
+        670.0 => 0.000148,
+        675.0 => 0.000103,
+        680.0 => 0.000072,
+        685.0 => 0.000050,
+        690.0 => 0.000035,
+        695.0 => 0.000025,
+        700.0 => 0.000018,
+        705.0 => 0.000013,
+        710.0 => 0.000009,
+        715.0 => 0.000007,
+        720.0 => 0.000005,
+        725.0 => 0.000003,
+        730.0 => 0.000003,
+        735.0 => 0.000002,
+        740.0 => 0.000001,
+        745.0 => 0.000001,
+        750.0 => 0.000001,
+        755.0 => 0.000001,
+        760.0 => 0.000001,
+        765.0 => 0.000000,
+        770.0 => 0.000000,
+        775.0 => 0.000000,
+        780.0 => 0.000000
+    );
+}
+
+/// Integrate `spd` weighted by the luminous efficiency function `v` over the
+/// domain of `spd`. Outside its own domain `v` is taken to be zero.
+fn weighted_integral(spd: &VSPD, v: &VSPD) -> f64 {
+    let start = spd.start().max(v.start());
+    let end = spd.end().min(v.end());
+    if start >= end {
+        return 0.0;
+    }
+    (spd * v).integrate_range(start, end, Integration::Trapezoidal)
+}
+
+/// Compute the luminous flux, in lumens, of the spectral radiant flux `spd`
+/// in W/nm, using the `y_bar` function of `cmf` as the luminous efficiency
+/// function.
 pub fn spd_to_lumens(spd: &VSPD, cmf: &CMF) -> f64 {
-    // should probably do a modified verison of ASTM E-308 here but for
-    // now just do a straight interpolated integration
-    let cmf = cmf.y_bar.align(spd.shape());
-    let s = spd.values().zip(cmf.values()).map(|(s, y)| s * y).sum::<f64>();
+    K_M * weighted_integral(spd, &cmf.y_bar)
+}
+
+/// Compute the photopic luminous flux, in lumens, of the spectral radiant flux
+/// `spd` in W/nm.
+pub fn luminous_flux(spd: &VSPD) -> f64 {
+    spd_to_lumens(spd, &CIE_1931_2_DEGREE)
+}
+
+/// Compute the scotopic luminous flux, in scotopic lumens, of the spectral
+/// radiant flux `spd` in W/nm.
+pub fn scotopic_luminous_flux(spd: &VSPD) -> f64 {
+    K_M_SCOTOPIC * weighted_integral(spd, &SCOTOPIC_1951)
+}
+
+/// Compute the total radiant power of `spd`, i.e. its integral over its whole
+/// domain.
+pub fn radiant_flux(spd: &VSPD) -> f64 {
+    spd.integrate(Integration::Trapezoidal)
+}
+
+/// Compute the photopic luminous efficacy of radiation of `spd`, in lm/W.
+pub fn luminous_efficacy(spd: &VSPD) -> f64 {
+    luminous_flux(spd) / radiant_flux(spd)
+}
+
+/// Compute the scotopic luminous efficacy of radiation of `spd`, in lm/W.
+pub fn scotopic_luminous_efficacy(spd: &VSPD) -> f64 {
+    scotopic_luminous_flux(spd) / radiant_flux(spd)
+}
+
+/// Compute the photopic luminous efficiency of `spd`, i.e. its luminous
+/// efficacy relative to [K_M].
+pub fn luminous_efficiency(spd: &VSPD) -> f64 {
+    luminous_efficacy(spd) / K_M
+}
+
+/// Compute the scotopic/photopic (S/P) ratio of `spd`.
+pub fn scotopic_photopic_ratio(spd: &VSPD) -> f64 {
+    scotopic_luminous_flux(spd) / luminous_flux(spd)
+}
+
+/// Convert spectral radiance in W/sr/m²/nm to photopic luminance in cd/m².
+pub fn radiance_to_luminance(radiance: &VSPD) -> f64 {
+    luminous_flux(radiance)
+}
+
+/// Convert spectral radiance in W/sr/m²/nm to scotopic luminance in scotopic
+/// cd/m².
+pub fn radiance_to_scotopic_luminance(radiance: &VSPD) -> f64 {
+    scotopic_luminous_flux(radiance)
+}
+
+/// Convert spectral irradiance in W/m²/nm to photopic illuminance in lux.
+pub fn irradiance_to_illuminance(irradiance: &VSPD) -> f64 {
+    luminous_flux(irradiance)
+}
+
+/// Convert spectral radiant intensity in W/sr/nm to luminous intensity in
+/// candela.
+pub fn radiant_intensity_to_luminous_intensity(intensity: &VSPD) -> f64 {
+    luminous_flux(intensity)
+}
+
+/// Compute the CIE 191 mesopic adaptation coefficient `m` for a field of
+/// photopic luminance `l_p` and scotopic luminance `l_s`, both in cd/m². `m`
+/// is 1 for photopic and 0 for scotopic adaptation.
+pub fn mesopic_adaptation(l_p: f64, l_s: f64) -> f64 {
+    mesopic(l_p, l_s).1
+}
+
+/// Compute the CIE 191 mesopic luminance, in cd/m², of a field of photopic
+/// luminance `l_p` and scotopic luminance `l_s`, both in cd/m².
+pub fn mesopic_luminance(l_p: f64, l_s: f64) -> f64 {
+    mesopic(l_p, l_s).0
+}
+
+/// Compute the CIE 191 mesopic luminance of spectral radiance in
+/// W/sr/m²/nm, in cd/m².
+pub fn radiance_to_mesopic_luminance(radiance: &VSPD) -> f64 {
+    mesopic_luminance(
+        radiance_to_luminance(radiance),
+        radiance_to_scotopic_luminance(radiance),
+    )
+}
+
+/// Returns the CIE 191 mesopic luminance and adaptation coefficient, found
+/// by iterating from `m = 0.5` until `m` converges.
+fn mesopic(l_p: f64, l_s: f64) -> (f64, f64) {
+    let luminance = |m: f64| {
+        (m * l_p + (1.0 - m) * l_s * V_PRIME_555)
+            / (m + (1.0 - m) * V_PRIME_555)
+    };
+
+    let mut m = 0.5;
+    for _ in 0..100 {
+        let l_mes = luminance(m);
+        let m_next = if l_mes >= MESOPIC_PHOTOPIC_LIMIT {
+            1.0
+        } else if l_mes <= MESOPIC_SCOTOPIC_LIMIT {
+            0.0
+        } else {
+            (MESOPIC_A + MESOPIC_B * l_mes.log10()).clamp(0.0, 1.0)
+        };
+        let converged = (m_next - m).abs() < 1e-12;
+        m = m_next;
+        if converged {
+            break;
+        }
+    }
+
+    (luminance(m), m)
+}
+
+/// Returns the CIE 191 mesopic luminous efficiency function V_mes(λ) for the
+/// adaptation coefficient `m`, normalized to a peak of 1.
+pub fn mesopic_luminous_efficiency(m: f64) -> VSPD {
+    let v = CIE_1931_2_DEGREE.y_bar.align(SCOTOPIC_1951.shape());
+    (m * &v + (1.0 - m) * &*SCOTOPIC_1951).normalize_peak()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(nm: f64) -> VSPD {
+        vspd!(
+            nm - 1.0 => 0.0,
+            nm => 1.0,
+            nm + 1.0 => 0.0
+        )
+    }
+
+    #[test]
+    fn photopic() {
+        // 1W at the peak of V is 683lm
+        assert!((luminous_efficacy(&line(555.0)) - K_M).abs() < 0.1);
+        assert!((luminous_efficiency(&line(555.0)) - 1.0).abs() < 1e-4);
+
+        // equal energy across the visible
+        let e = VSPD::constant(SpdShape::new(360.0, 830.0, 1.0), 1.0);
+        let sum_y = CIE_1931_2_DEGREE.y_bar.values().sum::<f64>();
+        assert!((luminous_flux(&e) - K_M * sum_y).abs() / (K_M * sum_y) < 1e-4);
+
+        // flux doesn't depend on the sampling interval
+        let e5 = VSPD::constant(SpdShape::new(360.0, 830.0, 5.0), 1.0);
+        assert!((luminous_flux(&e5) - luminous_flux(&e)).abs() < 0.5);
+        assert!(
+            (irradiance_to_illuminance(&(&e * 2.0))
+                - 2.0 * radiance_to_luminance(&e))
+            .abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn scotopic() {
+        assert!(
+            (scotopic_luminous_efficacy(&line(505.0)) - 0.998 * K_M_SCOTOPIC)
+                .abs()
+                < 1.0
+        );
+        // both scales agree at 555nm
+        let l = line(555.0);
+        assert!((scotopic_luminous_flux(&l) - luminous_flux(&l)).abs() < 1.0);
+        assert!(scotopic_photopic_ratio(&line(450.0)) > 10.0);
+    }
+
+    #[test]
+    fn mesopic_system() {
+        assert_eq!(mesopic_adaptation(10.0, 20.0), 1.0);
+        assert_eq!(mesopic_luminance(10.0, 20.0), 10.0);
+        assert_eq!(mesopic_adaptation(0.001, 0.002), 0.0);
+        assert!((mesopic_luminance(0.001, 0.002) - 0.002).abs() < 1e-15);
+
+        // with S/P = 1 the luminance is unchanged and m = a
+        assert!((mesopic_adaptation(1.0, 1.0) - MESOPIC_A).abs() < 1e-12);
+        assert!((mesopic_luminance(1.0, 1.0) - 1.0).abs() < 1e-12);
+
+        // a bluish source looks brighter at low light levels
+        let l_mes = mesopic_luminance(0.1, 0.2);
+        assert!(l_mes > 0.1 && l_mes < 0.2);
 
-    s * 683.0 / spd.len() as f64
-}
\ No newline at end of file
+        let v = mesopic_luminous_efficiency(1.0);
+        assert!((v.peak_wavelength() - 555.0).abs() < 1.0);
+        let v = mesopic_luminous_efficiency(0.0);
+        assert!((v.peak_wavelength() - 507.0).abs() < 1.0);
+    }
+}
@@ -580,6 +580,16 @@ impl From<RGBf64> for RGBf32 {
     }
 }
 
+impl From<RGBf32> for RGBf64 {
+    fn from(c: RGBf32) -> RGBf64 {
+        RGBf64 {
+            r: c.r as f64,
+            g: c.g as f64,
+            b: c.b as f64,
+        }
+    }
+}
+
 impl From<RGBf32> for RGBu8 {
     fn from(c: RGBf32) -> RGBu8 {
         RGBu8 {
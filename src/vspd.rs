@@ -2,6 +2,7 @@ use num_traits::{Float, FromPrimitive, ToPrimitive};
 
 use std::fmt::{Debug, Display};
 use std::iter::FromIterator;
+use std::ops::{Add, Div, Mul, Sub};
 
 use float_cmp::{ApproxEq, F64Margin};
 
@@ -11,7 +12,8 @@ use crate::{
     cmf::CMF,
     illuminant,
     interpolation::{
-        ExtrapolatorConstant, InterpolatorSprague, SpragueCoefficients,
+        ExtrapolatorConstant, InterpolatorLinear, InterpolatorSprague,
+        SpragueCoefficients,
     },
     xyz::{xyz, XYZf64},
 };
@@ -495,6 +497,364 @@ impl VSPD {
             }
         }
     }
+
+    /// Get the value of this SPD at wavelength `nm`, linearly interpolating
+    /// between samples and holding the end values outside the domain.
+    pub fn value_at(&self, nm: f64) -> f64 {
+        InterpolatorLinear::new(self).evaluate(nm)
+    }
+
+    /// Returns a new [VSPD] with `f` applied to each value.
+    fn map_values<F>(&self, f: F) -> VSPD
+    where
+        F: Fn(f64) -> f64,
+    {
+        VSPD {
+            samples: self
+                .samples
+                .iter()
+                .map(|s| Sample::new(s.nm, f(s.v)))
+                .collect(),
+            shape: self.shape,
+        }
+    }
+
+    /// Returns the values of this SPD at the wavelengths of `other`. If the
+    /// shapes differ this SPD is aligned to `other`, falling back to linear
+    /// interpolation when either is varying or their wavelengths are offset.
+    fn values_at_samples_of(&self, other: &VSPD) -> Vec<f64> {
+        if self.shape == other.shape && self.len() == other.len() {
+            return self.values().collect();
+        }
+
+        if let (Interval::Uniform(_), Interval::Uniform(_)) =
+            (self.interval(), other.interval())
+        {
+            let aligned = self.align(other.shape);
+            if aligned.len() == other.len()
+                && aligned
+                    .wavelengths()
+                    .zip(other.wavelengths())
+                    .all(|(a, b)| (a - b).abs() < 1e-9)
+            {
+                return aligned.values().collect();
+            }
+        }
+
+        let interp = InterpolatorLinear::new(self);
+        other.wavelengths().map(|nm| interp.evaluate(nm)).collect()
+    }
+
+    /// Returns a new [VSPD] with the shape of `self` whose values are
+    /// `f(self, rhs)`, with `rhs` aligned to `self` first.
+    fn zip_values<F>(&self, rhs: &VSPD, f: F) -> VSPD
+    where
+        F: Fn(f64, f64) -> f64,
+    {
+        let rhs = rhs.values_at_samples_of(self);
+        VSPD {
+            samples: self
+                .samples
+                .iter()
+                .zip(rhs.iter())
+                .map(|(s, r)| Sample::new(s.nm, f(s.v, *r)))
+                .collect(),
+            shape: self.shape,
+        }
+    }
+
+    /// Returns a new [VSPD] scaled so that its maximum value is 1.
+    /// # Panics
+    /// If the maximum value is not positive.
+    pub fn normalize_peak(&self) -> VSPD {
+        let peak = self.values().fold(f64::NEG_INFINITY, f64::max);
+        if peak <= 0.0 {
+            panic!("Cannot normalize a VSPD whose peak is {}", peak);
+        }
+        self / peak
+    }
+
+    /// Returns a new [VSPD] scaled so that its value at wavelength `nm` is 1.
+    /// The value at `nm` is found as in [value_at](VSPD::value_at).
+    /// # Panics
+    /// If the value at `nm` is zero.
+    pub fn normalize_at(&self, nm: f64) -> VSPD {
+        let v = self.value_at(nm);
+        if v == 0.0 {
+            panic!("Cannot normalize a VSPD that is zero at {}nm", nm);
+        }
+        self / v
+    }
+
+    /// Returns a new [VSPD] scaled so that its integral against the `y_bar`
+    /// function of `cmf` is 1, i.e. so that it has unit luminance.
+    /// # Panics
+    /// If the luminance is zero.
+    pub fn normalize_luminance(&self, cmf: &CMF) -> VSPD {
+        let y_bar = cmf.y_bar.values_at_samples_of(self);
+        let n = self.len();
+        // trapezoidal weights so that varying intervals are handled
+        let y = self
+            .samples
+            .iter()
+            .enumerate()
+            .zip(y_bar.iter())
+            .map(|((i, s), y)| {
+                let lo = self.samples[i.saturating_sub(1)].nm;
+                let hi = self.samples[(i + 1).min(n - 1)].nm;
+                s.v * y * (hi - lo) * 0.5
+            })
+            .sum::<f64>();
+        if y == 0.0 {
+            panic!("Cannot normalize a VSPD with zero luminance");
+        }
+        self / y
+    }
+
+    /// Returns a new [VSPD] with each value clamped to `[min, max]`.
+    pub fn clamp(&self, min: f64, max: f64) -> VSPD {
+        self.map_values(|v| v.max(min).min(max))
+    }
+
+    /// Returns a new [VSPD] with each value raised to the power `e`.
+    pub fn powf(&self, e: f64) -> VSPD {
+        self.map_values(|v| v.powf(e))
+    }
+
+    /// Integrate this SPD over its whole domain using the given [Integration]
+    /// rule.
+    pub fn integrate(&self, method: Integration) -> f64 {
+        self.integrate_range(self.start(), self.end(), method)
+    }
+
+    /// Integrate this SPD between wavelengths `start` and `end` using the
+    /// given [Integration] rule. The range is clipped to the SPD's domain and
+    /// the values at its boundaries are found as in [value_at](VSPD::value_at)
+    /// when they do not fall on a sample.
+    pub fn integrate_range(
+        &self,
+        start: f64,
+        end: f64,
+        method: Integration,
+    ) -> f64 {
+        let start = start.max(self.start());
+        let end = end.min(self.end());
+        if start >= end {
+            return 0.0;
+        }
+
+        let mut samples = Vec::with_capacity(self.len() + 2);
+        if self.samples.iter().all(|s| s.nm != start) {
+            samples.push(Sample::new(start, self.value_at(start)));
+        }
+        samples.extend(
+            self.samples
+                .iter()
+                .filter(|s| s.nm >= start && s.nm <= end)
+                .cloned(),
+        );
+        if self.samples.iter().all(|s| s.nm != end) {
+            samples.push(Sample::new(end, self.value_at(end)));
+        }
+
+        match method {
+            Integration::Rectangular => integrate_rectangular(&samples),
+            Integration::Trapezoidal => integrate_trapezoidal(&samples),
+            Integration::Simpson => integrate_simpson(&samples),
+        }
+    }
+
+    /// Get the wavelength of the highest peak of this SPD. The location is
+    /// refined by fitting a parabola through the highest sample and its
+    /// neighbours.
+    pub fn peak_wavelength(&self) -> f64 {
+        self.peak().1
+    }
+
+    /// Returns the index of the highest sample, and the wavelength and value
+    /// of the vertex of the parabola through it and its neighbours.
+    fn peak(&self) -> (usize, f64, f64) {
+        let (i, _) = self.samples.iter().enumerate().fold(
+            (0, f64::NEG_INFINITY),
+            |(im, vm), (i, s)| if s.v > vm { (i, s.v) } else { (im, vm) },
+        );
+        if i == 0 || i == self.len() - 1 {
+            return (i, self.samples[i].nm, self.samples[i].v);
+        }
+
+        let (x0, y0) = (self.samples[i - 1].nm, self.samples[i - 1].v);
+        let (x1, y1) = (self.samples[i].nm, self.samples[i].v);
+        let (x2, y2) = (self.samples[i + 1].nm, self.samples[i + 1].v);
+        let d = (x1 - x0) * (y1 - y2) - (x1 - x2) * (y1 - y0);
+        if d == 0.0 {
+            return (i, x1, y1);
+        }
+        let x = x1
+            - 0.5
+                * ((x1 - x0).powi(2) * (y1 - y2)
+                    - (x1 - x2).powi(2) * (y1 - y0))
+                / d;
+        // evaluate the parabola at its vertex in Lagrange form
+        let y = y0 * (x - x1) * (x - x2) / ((x0 - x1) * (x0 - x2))
+            + y1 * (x - x0) * (x - x2) / ((x1 - x0) * (x1 - x2))
+            + y2 * (x - x0) * (x - x1) / ((x2 - x0) * (x2 - x1));
+        (i, x, y)
+    }
+
+    /// Get the full width at half maximum of the highest peak of this SPD, in
+    /// nm. The maximum is refined as in
+    /// [peak_wavelength](VSPD::peak_wavelength) and the half-maximum crossings
+    /// are linearly interpolated between samples. Returns `None` if the SPD
+    /// does not fall to half its maximum on both sides of the peak.
+    pub fn fwhm(&self) -> Option<f64> {
+        let (i, _, peak) = self.peak();
+        let half = peak * 0.5;
+        let crossing = |a: &Sample, b: &Sample| {
+            a.nm + (half - a.v) / (b.v - a.v) * (b.nm - a.nm)
+        };
+
+        let lo = (0..i)
+            .rev()
+            .find(|j| self.samples[*j].v <= half)
+            .map(|j| crossing(&self.samples[j], &self.samples[j + 1]))?;
+        let hi = (i + 1..self.len())
+            .find(|j| self.samples[*j].v <= half)
+            .map(|j| crossing(&self.samples[j - 1], &self.samples[j]))?;
+
+        Some(hi - lo)
+    }
+
+    /// Get the centroid wavelength of this SPD, i.e. the mean wavelength
+    /// weighted by power, using trapezoidal integration.
+    pub fn centroid(&self) -> f64 {
+        let weighted: Vec<Sample> = self
+            .samples
+            .iter()
+            .map(|s| Sample::new(s.nm, s.nm * s.v))
+            .collect();
+        integrate_trapezoidal(&weighted) / integrate_trapezoidal(&self.samples)
+    }
+
+    /// Integrate this SPD over a wavelength band such as [band::UV_A], using
+    /// trapezoidal integration.
+    pub fn band_sum(&self, band: (f64, f64)) -> f64 {
+        self.integrate_range(band.0, band.1, Integration::Trapezoidal)
+    }
+
+    /// Get the photon flux of this SPD between `start` and `end`, in µmol/s
+    /// per unit of the SPD's power, assuming the values are spectral power in
+    /// W/nm (or irradiance in W/m²/nm, giving µmol/m²/s).
+    pub fn photon_flux(&self, start: f64, end: f64) -> f64 {
+        // photons per joule at 1nm is 1e-9 / (h·c); divide by Avogadro's
+        // number and scale to µmol
+        const H: f64 = 6.62607015e-34;
+        const C: f64 = 299792458.0;
+        const N_A: f64 = 6.02214076e23;
+        let k = 1e-9 / (H * C) / N_A * 1e6;
+
+        let photons: Vec<Sample> = self
+            .samples
+            .iter()
+            .map(|s| Sample::new(s.nm, s.v * s.nm * k))
+            .collect();
+        VSPD::new(photons).integrate_range(start, end, Integration::Trapezoidal)
+    }
+
+    /// Get the photosynthetic photon flux density of this SPD, i.e. the photon
+    /// flux in the [band::PAR] range. With irradiance in W/m²/nm this is in
+    /// µmol/m²/s.
+    pub fn ppfd(&self) -> f64 {
+        self.photon_flux(band::PAR.0, band::PAR.1)
+    }
+}
+
+/// Numerical integration rules for [VSPD::integrate].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Integration {
+    /// Each sample multiplied by the interval to the next sample, with the
+    /// last sample using the interval before it. For uniform SPDs this is the
+    /// sum of the values times the interval.
+    Rectangular,
+    /// The trapezoidal rule.
+    Trapezoidal,
+    /// Composite Simpson's rule, in its form for irregularly spaced samples.
+    Simpson,
+}
+
+/// Wavelength bands, in nm, for use with [VSPD::band_sum].
+pub mod band {
+    /// Ultraviolet C
+    pub const UV_C: (f64, f64) = (100.0, 280.0);
+    /// Ultraviolet B
+    pub const UV_B: (f64, f64) = (280.0, 315.0);
+    /// Ultraviolet A
+    pub const UV_A: (f64, f64) = (315.0, 400.0);
+    /// The visible range used for colorimetry
+    pub const VISIBLE: (f64, f64) = (380.0, 780.0);
+    /// Photosynthetically active radiation
+    pub const PAR: (f64, f64) = (400.0, 700.0);
+    /// Extended PAR, including far red
+    pub const EPAR: (f64, f64) = (400.0, 750.0);
+    /// Blue band used in horticulture
+    pub const BLUE: (f64, f64) = (400.0, 500.0);
+    /// Green band used in horticulture
+    pub const GREEN: (f64, f64) = (500.0, 600.0);
+    /// Red band used in horticulture
+    pub const RED: (f64, f64) = (600.0, 700.0);
+    /// Far red band used in horticulture
+    pub const FAR_RED: (f64, f64) = (700.0, 750.0);
+}
+
+fn integrate_rectangular(samples: &[Sample]) -> f64 {
+    let n = samples.len();
+    if n < 2 {
+        return 0.0;
+    }
+    samples
+        .windows(2)
+        .map(|w| w[0].v * (w[1].nm - w[0].nm))
+        .sum::<f64>()
+        + samples[n - 1].v * (samples[n - 1].nm - samples[n - 2].nm)
+}
+
+fn integrate_trapezoidal(samples: &[Sample]) -> f64 {
+    samples
+        .windows(2)
+        .map(|w| 0.5 * (w[0].v + w[1].v) * (w[1].nm - w[0].nm))
+        .sum()
+}
+
+fn integrate_simpson(samples: &[Sample]) -> f64 {
+    let n = samples.len() - 1;
+    if n < 2 {
+        return integrate_trapezoidal(samples);
+    }
+    let h = |i: usize| samples[i + 1].nm - samples[i].nm;
+    let f = |i: usize| samples[i].v;
+
+    let mut result = 0.0;
+    for i in (1..n).step_by(2) {
+        let (h0, h1) = (h(i - 1), h(i));
+        let hph = h1 + h0;
+        let hdh = h1 / h0;
+        let hmh = h1 * h0;
+        result += hph / 6.0
+            * ((2.0 - hdh) * f(i - 1)
+                + hph * hph / hmh * f(i)
+                + (2.0 - 1.0 / hdh) * f(i + 1));
+    }
+
+    // an odd number of intervals leaves one over, which is integrated with
+    // the parabola through the last three samples
+    if n % 2 == 1 {
+        let (h0, h1) = (h(n - 2), h(n - 1));
+        let alpha = (2.0 * h1 * h1 + 3.0 * h0 * h1) / (6.0 * (h0 + h1));
+        let beta = (h1 * h1 + 3.0 * h1 * h0) / (6.0 * h0);
+        let eta = h1 * h1 * h1 / (6.0 * h0 * (h0 + h1));
+        result += alpha * f(n) + beta * f(n - 1) - eta * f(n - 2);
+    }
+
+    result
 }
 
 fn calculate_interval(samples: &[Sample]) -> Interval<f64> {
@@ -883,6 +1243,75 @@ impl FromIterator<(f64, f64)> for VSPD {
     }
 }
 
+/// Implements an arithmetic operator between [VSPD]s, and between a [VSPD]
+/// and an `f64`, for all combinations of owned and borrowed operands. When
+/// two SPDs have different shapes, the right-hand side is aligned to the
+/// shape of the left-hand side.
+macro_rules! impl_vspd_op {
+    ($op:ident, $method:ident, $f:expr) => {
+        impl $op<&VSPD> for &VSPD {
+            type Output = VSPD;
+            fn $method(self, rhs: &VSPD) -> VSPD {
+                self.zip_values(rhs, $f)
+            }
+        }
+
+        impl $op<VSPD> for &VSPD {
+            type Output = VSPD;
+            fn $method(self, rhs: VSPD) -> VSPD {
+                self.zip_values(&rhs, $f)
+            }
+        }
+
+        impl $op<&VSPD> for VSPD {
+            type Output = VSPD;
+            fn $method(self, rhs: &VSPD) -> VSPD {
+                self.zip_values(rhs, $f)
+            }
+        }
+
+        impl $op<VSPD> for VSPD {
+            type Output = VSPD;
+            fn $method(self, rhs: VSPD) -> VSPD {
+                self.zip_values(&rhs, $f)
+            }
+        }
+
+        impl $op<f64> for &VSPD {
+            type Output = VSPD;
+            fn $method(self, rhs: f64) -> VSPD {
+                self.map_values(|v| $f(v, rhs))
+            }
+        }
+
+        impl $op<f64> for VSPD {
+            type Output = VSPD;
+            fn $method(self, rhs: f64) -> VSPD {
+                self.map_values(|v| $f(v, rhs))
+            }
+        }
+
+        impl $op<&VSPD> for f64 {
+            type Output = VSPD;
+            fn $method(self, rhs: &VSPD) -> VSPD {
+                rhs.map_values(|v| $f(self, v))
+            }
+        }
+
+        impl $op<VSPD> for f64 {
+            type Output = VSPD;
+            fn $method(self, rhs: VSPD) -> VSPD {
+                rhs.map_values(|v| $f(self, v))
+            }
+        }
+    };
+}
+
+impl_vspd_op!(Add, add, |a: f64, b: f64| a + b);
+impl_vspd_op!(Sub, sub, |a: f64, b: f64| a - b);
+impl_vspd_op!(Mul, mul, |a: f64, b: f64| a * b);
+impl_vspd_op!(Div, div, |a: f64, b: f64| a / b);
+
 impl<'a> ApproxEq for &'a VSPD {
     type Margin = F64Margin;
     fn approx_eq<T: Into<Self::Margin>>(self, other: Self, margin: T) -> bool {
@@ -1154,4 +1583,178 @@ mod tests {
             ));
         }
     }
+
+    #[test]
+    fn arithmetic() {
+        let a = vspd!(
+            400.0f64 => 1.0,
+            420.0 => 2.0,
+            440.0 => 3.0,
+            460.0 => 4.0,
+            480.0 => 5.0,
+            500.0 => 6.0,
+        );
+        let b = VSPD::constant(SpdShape::new(400.0, 500.0, 20.0), 2.0);
+
+        assert_eq!(&a + &b, a.map_values(|v| v + 2.0));
+        assert_eq!(&a - &b, a.map_values(|v| v - 2.0));
+        assert_eq!(&a * &b, a.map_values(|v| v * 2.0));
+        assert_eq!(&a / &b, a.map_values(|v| v / 2.0));
+        assert_eq!(&a * 2.0, &a * &b);
+        assert_eq!(2.0 * &a, &a * 2.0);
+        assert_eq!(12.0 / &a, a.map_values(|v| 12.0 / v));
+        assert_eq!(a.clone() + b.clone(), &a + &b);
+
+        // a differently shaped rhs is aligned to the lhs
+        let c = VSPD::constant(SpdShape::new(380.0, 700.0, 5.0), 0.5);
+        let ac = &a * &c;
+        assert!(ac.shape() == a.shape());
+        assert!(ac.approx_eq(
+            &(&a * 0.5),
+            F64Margin {
+                ulps: 2,
+                epsilon: 1e-15
+            }
+        ));
+
+        // including a varying one
+        let d = vspd!(
+            390.0f64 => 1.0,
+            450.0 => 1.0,
+            460.0 => 3.0,
+            520.0 => 3.0,
+        );
+        let ad = &a * &d;
+        assert_eq!(
+            ad.values().collect::<Vec<_>>(),
+            vec![1.0, 2.0, 3.0, 12.0, 15.0, 18.0]
+        );
+    }
+
+    #[test]
+    fn normalize() {
+        let a = vspd!(
+            400.0f64 => 1.0,
+            420.0 => 2.0,
+            440.0 => 4.0,
+            460.0 => 2.0,
+            480.0 => 1.0,
+            500.0 => 0.5,
+        );
+        assert_eq!(
+            a.normalize_peak().values().collect::<Vec<_>>(),
+            vec![0.25, 0.5, 1.0, 0.5, 0.25, 0.125]
+        );
+        assert_eq!(a.normalize_at(420.0).value_at(420.0), 1.0);
+        assert_eq!(a.value_at(430.0), 3.0);
+        assert_eq!(a.normalize_at(430.0).value_at(440.0), 4.0 / 3.0);
+
+        let e = VSPD::constant(SpdShape::new(360.0, 830.0, 1.0), 5.0);
+        let e = e.normalize_luminance(&cmf::CIE_1931_2_DEGREE);
+        let sum_y = cmf::CIE_1931_2_DEGREE
+            .y_bar
+            .align(e.shape())
+            .values()
+            .sum::<f64>();
+        assert!((e.first().v * sum_y - 1.0).abs() < 1e-3);
+
+        assert_eq!(
+            a.clamp(0.75, 2.0).values().collect::<Vec<_>>(),
+            vec![1.0, 2.0, 2.0, 2.0, 1.0, 0.75]
+        );
+        assert_eq!(
+            a.powf(2.0).values().collect::<Vec<_>>(),
+            vec![1.0, 4.0, 16.0, 4.0, 1.0, 0.25]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn normalize_zero() {
+        VSPD::constant(SpdShape::new(400.0, 500.0, 20.0), 0.0).normalize_peak();
+    }
+
+    #[test]
+    fn integration() {
+        // a quadratic is integrated exactly by Simpson's rule, with even or
+        // odd numbers of intervals and with varying spacing
+        let quadratic = |start: f64, end: f64, step: f64| -> VSPD {
+            SpdShape::new(start, end, step)
+                .iter()
+                .map(|nm| (nm, (nm - 400.0).powi(2)))
+                .collect()
+        };
+        let exact = |end: f64| (end - 400.0).powi(3) / 3.0;
+
+        let spd = quadratic(400.0, 500.0, 10.0);
+        assert!(
+            (spd.integrate(Integration::Simpson) - exact(500.0)).abs() < 1e-8
+        );
+        assert!(
+            (spd.integrate(Integration::Trapezoidal) - 335000.0).abs() < 1e-8
+        );
+        assert!(
+            (spd.integrate(Integration::Rectangular)
+                - spd.values().sum::<f64>() * 10.0)
+                .abs()
+                < 1e-8
+        );
+
+        let spd = quadratic(400.0, 490.0, 10.0);
+        assert!(
+            (spd.integrate(Integration::Simpson) - exact(490.0)).abs() < 1e-8
+        );
+
+        let spd: VSPD = [400.0, 405.0, 420.0, 430.0, 460.0, 470.0, 500.0]
+            .iter()
+            .map(|nm| (*nm, (nm - 400.0).powi(2)))
+            .collect();
+        assert!(
+            (spd.integrate(Integration::Simpson) - exact(500.0)).abs() < 1e-8
+        );
+
+        // partial ranges interpolate their end points
+        let spd = VSPD::constant(SpdShape::new(400.0, 500.0, 10.0), 2.0);
+        for method in [Integration::Trapezoidal, Integration::Simpson].iter() {
+            let s = spd.integrate_range(405.0, 455.0, *method);
+            assert!((s - 100.0).abs() < 1e-10);
+        }
+        assert!((spd.band_sum(band::UV_A) - 0.0).abs() < 1e-15);
+        assert!((spd.band_sum(band::BLUE) - 200.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn peak_statistics() {
+        let sigma = 10.0;
+        let mean = 552.3;
+        let spd: VSPD = SpdShape::new(400.0, 700.0, 5.0)
+            .iter()
+            .map(|nm: f64| {
+                (nm, 3.0 * (-0.5 * ((nm - mean) / sigma).powi(2)).exp())
+            })
+            .collect();
+
+        assert!((spd.peak_wavelength() - mean).abs() < 0.2);
+        let fwhm = 2.0 * (2.0 * 2.0f64.ln()).sqrt() * sigma;
+        assert!((spd.fwhm().unwrap() - fwhm).abs() < 0.2);
+        assert!((spd.centroid() - mean).abs() < 1e-6);
+
+        // a peak at the edge has no half maximum on one side
+        let ramp = VSPD::from_values(
+            SpdShape::new(400.0, 450.0, 10.0),
+            &[0.0, 0.2, 0.4, 0.6, 0.8, 1.0],
+        );
+        assert_eq!(ramp.peak_wavelength(), 450.0);
+        assert!(ramp.fwhm().is_none());
+    }
+
+    #[test]
+    fn photon_flux() {
+        // 1 W/m²/nm across PAR
+        let spd = VSPD::constant(SpdShape::new(380.0, 780.0, 5.0), 1.0);
+        let k = 1e-9 / (6.62607015e-34 * 299792458.0 * 6.02214076e23) * 1e6;
+        let expected = k * (700.0f64.powi(2) - 400.0f64.powi(2)) / 2.0;
+        assert!((spd.ppfd() - expected).abs() < 1e-9);
+        assert!((spd.ppfd() - 1379.3).abs() < 0.1);
+    }
 }